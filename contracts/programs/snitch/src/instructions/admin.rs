@@ -3,6 +3,8 @@ use solana_program::clock::Clock;
 
 use crate::state::*;
 use crate::SnitchError;
+use crate::{AuthorityProposed, AuthorityTransferred, RoleAssigned};
+use crate::{ConfigProposed, ConfigApplied, ConfigCancelled};
 
 // ==================== Admin Instructions ====================
 
@@ -16,6 +18,22 @@ pub struct AdminOnly<'info> {
         seeds = [b"game_state"],
         bump = game_state.bump,
         constraint = game_state.authority == admin.key() @ SnitchError::Unauthorized,
+        constraint = game_state.multisig.threshold == 0 @ SnitchError::MultisigRequired,
+    )]
+    pub game_state: Account<'info, GameState>,
+}
+
+/// Pausing stays single-key so it can be used in an emergency; resuming needs
+/// the multisig once it is enabled
+#[derive(Accounts)]
+pub struct PauserOnly<'info> {
+    pub pauser: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"game_state"],
+        bump = game_state.bump,
+        constraint = game_state.has_role(Role::Pauser, &pauser.key()) @ SnitchError::Unauthorized,
     )]
     pub game_state: Account<'info, GameState>,
 }
@@ -29,7 +47,8 @@ pub struct UpdateConfig<'info> {
         mut,
         seeds = [b"game_state"],
         bump = game_state.bump,
-        constraint = game_state.authority == admin.key() @ SnitchError::Unauthorized,
+        constraint = game_state.has_role(Role::ConfigManager, &admin.key()) @ SnitchError::Unauthorized,
+        constraint = game_state.multisig.threshold == 0 @ SnitchError::MultisigRequired,
     )]
     pub game_state: Account<'info, GameState>,
 }
@@ -38,41 +57,116 @@ pub fn update_config_handler(
     ctx: Context<UpdateConfig>,
     config: GameConfig,
 ) -> Result<()> {
-    // Validate new configuration
-    config.validate()?;
+    let game_state = &mut ctx.accounts.game_state;
+    let now = Clock::get()?.unix_timestamp;
+    
+    // Validate and stage the new configuration behind the timelock
+    let activates_at = game_state.propose_config(config.clone(), now)?;
+    
+    emit!(ConfigProposed {
+        proposed_by: ctx.accounts.admin.key(),
+        config,
+        activates_at,
+        timestamp: now,
+    });
     
+    msg!("Game configuration proposed, applies at {}", activates_at);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApplyConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"game_state"],
+        bump = game_state.bump,
+    )]
+    pub game_state: Account<'info, GameState>,
+}
+
+/// Permissionless: anyone may apply a pending config once its timelock has passed
+pub fn apply_config_handler(ctx: Context<ApplyConfig>) -> Result<()> {
     let game_state = &mut ctx.accounts.game_state;
-    game_state.config = config;
+    let now = Clock::get()?.unix_timestamp;
     
-    msg!("Game configuration updated by admin");
+    require!(
+        now >= game_state.pending_config_activates_at,
+        SnitchError::ConfigTimelockActive
+    );
+    let config = game_state.pending_config
+        .take()
+        .ok_or(SnitchError::NoPendingConfig)?;
+    
+    game_state.config = config.clone();
+    game_state.pending_config_activates_at = 0;
+    
+    emit!(ConfigApplied {
+        config,
+        timestamp: now,
+    });
+    
+    msg!("Game configuration applied");
     
     Ok(())
 }
 
-pub fn pause_handler(ctx: Context<AdminOnly>) -> Result<()> {
+pub fn cancel_config_handler(ctx: Context<UpdateConfig>) -> Result<()> {
+    let game_state = &mut ctx.accounts.game_state;
+    
+    require!(game_state.pending_config.is_some(), SnitchError::NoPendingConfig);
+    
+    game_state.pending_config = None;
+    game_state.pending_config_activates_at = 0;
+    
+    emit!(ConfigCancelled {
+        cancelled_by: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Pending game configuration cancelled");
+    
+    Ok(())
+}
+
+/// The timelock can only be raised, so it can't be used to shortcut a pending change,
+/// and is capped at `GameState::MAX_CONFIG_TIMELOCK`
+pub fn set_config_timelock_handler(
+    ctx: Context<AdminOnly>,
+    timelock: i64,
+) -> Result<()> {
+    ctx.accounts.game_state.set_config_timelock(timelock)?;
+    
+    msg!("Config timelock set to {}s", timelock);
+    
+    Ok(())
+}
+
+pub fn pause_handler(ctx: Context<PauserOnly>) -> Result<()> {
     let game_state = &mut ctx.accounts.game_state;
     
     require!(!game_state.paused, SnitchError::InvalidConfig);
     
     game_state.paused = true;
     
-    msg!("Game paused by admin {} at {}", 
-        ctx.accounts.admin.key(),
+    msg!("Game paused by {} at {}", 
+        ctx.accounts.pauser.key(),
         Clock::get()?.unix_timestamp
     );
     
     Ok(())
 }
 
-pub fn resume_handler(ctx: Context<AdminOnly>) -> Result<()> {
+pub fn resume_handler(ctx: Context<PauserOnly>) -> Result<()> {
     let game_state = &mut ctx.accounts.game_state;
     
+    require!(game_state.multisig.threshold == 0, SnitchError::MultisigRequired);
     require!(game_state.paused, SnitchError::InvalidConfig);
     
     game_state.paused = false;
     
-    msg!("Game resumed by admin {} at {}", 
-        ctx.accounts.admin.key(),
+    msg!("Game resumed by {} at {}", 
+        ctx.accounts.pauser.key(),
         Clock::get()?.unix_timestamp
     );
     
@@ -87,7 +181,8 @@ pub struct ResetCooldown<'info> {
     #[account(
         seeds = [b"game_state"],
         bump = game_state.bump,
-        constraint = game_state.authority == admin.key() @ SnitchError::Unauthorized,
+        constraint = game_state.has_role(Role::Selector, &admin.key()) @ SnitchError::Unauthorized,
+        constraint = game_state.multisig.threshold == 0 @ SnitchError::MultisigRequired,
     )]
     pub game_state: Account<'info, GameState>,
     
@@ -115,3 +210,75 @@ pub fn reset_cooldown_handler(
     
     Ok(())
 }
+
+pub fn propose_authority_handler(
+    ctx: Context<AdminOnly>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    let game_state = &mut ctx.accounts.game_state;
+    
+    game_state.propose_authority(new_authority)?;
+    
+    emit!(AuthorityProposed {
+        authority: game_state.authority,
+        pending_authority: new_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Authority transfer to {} proposed", new_authority);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    pub new_authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"game_state"],
+        bump = game_state.bump,
+        constraint = game_state.pending_authority == Some(new_authority.key()) @ SnitchError::Unauthorized,
+    )]
+    pub game_state: Account<'info, GameState>,
+}
+
+pub fn accept_authority_handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let game_state = &mut ctx.accounts.game_state;
+    let previous_authority = game_state.authority;
+    
+    game_state.authority = ctx.accounts.new_authority.key();
+    game_state.pending_authority = None;
+    
+    emit!(AuthorityTransferred {
+        previous_authority,
+        new_authority: game_state.authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Authority transferred from {} to {}", previous_authority, game_state.authority);
+    
+    Ok(())
+}
+
+pub fn assign_role_handler(
+    ctx: Context<AdminOnly>,
+    role: Role,
+    holder: Option<Pubkey>,
+) -> Result<()> {
+    let game_state = &mut ctx.accounts.game_state;
+    let previous_holder = game_state.roles.holder(role);
+    
+    game_state.roles.set(role, holder);
+    
+    emit!(RoleAssigned {
+        role,
+        previous_holder,
+        holder,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Role {:?} assigned to {:?}", role, holder);
+    
+    Ok(())
+}
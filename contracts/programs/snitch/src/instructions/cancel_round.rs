@@ -0,0 +1,224 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer, Token, TokenAccount};
+use solana_program::clock::Clock;
+
+use crate::state::*;
+use crate::SnitchError;
+use crate::RoundCancelled;
+
+#[derive(Accounts)]
+pub struct CancelRound<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"game_state"],
+        bump = game_state.bump,
+        constraint = game_state.has_role(Role::Treasury, &admin.key()) @ SnitchError::Unauthorized,
+        // Refunds move vault funds, which a single key may no longer do once the multisig is on
+        constraint = game_state.multisig.threshold == 0 @ SnitchError::MultisigRequired,
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        constraint = interrogation.key() == game_state.active_interrogation.unwrap_or_default() @ SnitchError::NoActiveRound,
+    )]
+    pub interrogation: Account<'info, Interrogation>,
+    
+    #[account(
+        mut,
+        seeds = [b"player", interrogation.player_a.as_ref()],
+        bump,
+    )]
+    pub player_a_profile: Account<'info, PlayerProfile>,
+    
+    #[account(
+        mut,
+        seeds = [b"player", interrogation.player_b.as_ref()],
+        bump,
+    )]
+    pub player_b_profile: Account<'info, PlayerProfile>,
+    
+    /// Player A's token account (receives refunds)
+    #[account(
+        mut,
+        constraint = player_a_token.owner == interrogation.player_a @ SnitchError::Unauthorized,
+        constraint = player_a_token.mint == game_state.token_mint @ SnitchError::Unauthorized,
+    )]
+    pub player_a_token: Account<'info, TokenAccount>,
+    
+    /// Player B's token account (receives refunds)
+    #[account(
+        mut,
+        constraint = player_b_token.owner == interrogation.player_b @ SnitchError::Unauthorized,
+        constraint = player_b_token.mint == game_state.token_mint @ SnitchError::Unauthorized,
+    )]
+    pub player_b_token: Account<'info, TokenAccount>,
+    
+    /// Yield vault (refunds lawyer and revision fees)
+    #[account(
+        mut,
+        seeds = [b"yield_vault", game_state.key().as_ref()],
+        bump,
+    )]
+    pub yield_vault: Account<'info, TokenAccount>,
+    
+    /// Side pot holding this round's double-down wagers
+    #[account(
+        mut,
+        seeds = [b"side_pot", interrogation.key().as_ref()],
+        bump,
+    )]
+    pub side_pot: Account<'info, TokenAccount>,
+    
+    /// Escrow holding this round's bribe offers
+    #[account(
+        mut,
+        seeds = [b"bribe_escrow", interrogation.key().as_ref()],
+        bump,
+    )]
+    pub bribe_escrow: Account<'info, TokenAccount>,
+    
+    /// CHECK: Token authority (PDA)
+    #[account(
+        seeds = [b"game_state"],
+        bump = game_state.bump,
+    )]
+    pub token_authority: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<CancelRound>) -> Result<()> {
+    let game_state = &mut ctx.accounts.game_state;
+    let interrogation = &mut ctx.accounts.interrogation;
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    
+    require!(
+        interrogation.outcome == Outcome::Pending,
+        SnitchError::AlreadyResolved
+    );
+    
+    // Everything either player put up this round goes back to them,
+    // including lawyer and revision fees paid into the yield pool
+    let fees_a = interrogation.pnl_a.fees;
+    let fees_b = interrogation.pnl_b.fees;
+    let refund_a = [
+        (ctx.accounts.side_pot.to_account_info(), interrogation.side_pot_a),
+        (ctx.accounts.bribe_escrow.to_account_info(), interrogation.bribe_a),
+        (ctx.accounts.yield_vault.to_account_info(), fees_a),
+    ];
+    let refund_b = [
+        (ctx.accounts.side_pot.to_account_info(), interrogation.side_pot_b),
+        (ctx.accounts.bribe_escrow.to_account_info(), interrogation.bribe_b),
+        (ctx.accounts.yield_vault.to_account_info(), fees_b),
+    ];
+    
+    let seeds = &[
+        b"game_state",
+        &[game_state.bump],
+    ];
+    let signer = &[&seeds[..]];
+    
+    for (from, amount) in refund_a.into_iter().filter(|(_, amount)| *amount > 0) {
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from,
+                to: ctx.accounts.player_a_token.to_account_info(),
+                authority: ctx.accounts.token_authority.to_account_info(),
+            },
+            signer,
+        );
+        token::transfer(transfer_ctx, amount)?;
+    }
+    
+    for (from, amount) in refund_b.into_iter().filter(|(_, amount)| *amount > 0) {
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from,
+                to: ctx.accounts.player_b_token.to_account_info(),
+                authority: ctx.accounts.token_authority.to_account_info(),
+            },
+            signer,
+        );
+        token::transfer(transfer_ctx, amount)?;
+    }
+    
+    let refunded_a = interrogation.side_pot_a
+        .saturating_add(interrogation.bribe_a)
+        .saturating_add(fees_a);
+    let refunded_b = interrogation.side_pot_b
+        .saturating_add(interrogation.bribe_b)
+        .saturating_add(fees_b);
+    
+    // Fees were credited to the yield pool when paid
+    game_state.yield_pool = game_state.yield_pool
+        .saturating_sub(fees_a)
+        .saturating_sub(fees_b);
+    
+    interrogation.pnl_a.fees = 0;
+    interrogation.pnl_b.fees = 0;
+    
+    // Mark interrogation cancelled
+    interrogation.outcome = Outcome::Cancelled;
+    interrogation.phase = Phase::Resolved;
+    interrogation.resolved_at = now;
+    
+    // Free the game for a new round
+    game_state.active_interrogation = None;
+    
+    // Release both players and undo what the round counted against them
+    release_player(
+        &mut ctx.accounts.player_a_profile,
+        interrogation.choice_a.filter(|_| interrogation.revealed_a),
+        fees_a,
+        interrogation.previous_selected_at_a,
+    );
+    release_player(
+        &mut ctx.accounts.player_b_profile,
+        interrogation.choice_b.filter(|_| interrogation.revealed_b),
+        fees_b,
+        interrogation.previous_selected_at_b,
+    );
+    
+    // Emit event
+    emit!(RoundCancelled {
+        round_id: interrogation.round_id,
+        player_a: interrogation.player_a,
+        player_b: interrogation.player_b,
+        refunded_a,
+        refunded_b,
+        cancelled_by: ctx.accounts.admin.key(),
+        timestamp: now,
+    });
+    
+    msg!("Round {} cancelled by admin {}", 
+        interrogation.round_id, 
+        ctx.accounts.admin.key()
+    );
+    
+    Ok(())
+}
+
+/// Roll a player's profile back to where it was before the cancelled round
+fn release_player(
+    profile: &mut PlayerProfile,
+    revealed_choice: Option<Choice>,
+    fees_refunded: u64,
+    previous_selected_at: i64,
+) {
+    profile.current_round = 0;
+    profile.clear_cooldown();
+    profile.last_selected_at = previous_selected_at;
+    profile.times_selected = profile.times_selected.saturating_sub(1);
+    if let Some(choice) = revealed_choice {
+        profile.unrecord_choice(choice);
+    }
+    profile.refund_penalty(fees_refunded);
+    profile.refund_loss(fees_refunded);
+}
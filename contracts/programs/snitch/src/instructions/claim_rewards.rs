@@ -12,6 +12,7 @@ pub struct ClaimRewards<'info> {
     pub player: Signer<'info>,
     
     #[account(
+        mut,
         seeds = [b"game_state"],
         bump = game_state.bump,
     )]
@@ -88,11 +89,8 @@ pub fn handler(ctx: Context<ClaimRewards>) -> Result<()> {
     
     token::transfer(transfer_ctx, amount)?;
     
-    // Update yield pool
+    // The yield pool already excludes credited rewards (reserved at resolution)
     let game_state = &mut ctx.accounts.game_state;
-    game_state.yield_pool = game_state.yield_pool
-        .checked_sub(amount)
-        .ok_or(SnitchError::Underflow)?;
     game_state.total_rewards_distributed = game_state.total_rewards_distributed
         .checked_add(amount)
         .ok_or(SnitchError::Overflow)?;
@@ -0,0 +1,220 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount};
+use solana_program::clock::Clock;
+
+use crate::state::*;
+use crate::SnitchError;
+use crate::{InterrogationClosed, ProfileClosed};
+
+/// Accounts for closing a finished round (permissionless, rent goes to the original payer)
+#[derive(Accounts)]
+pub struct CloseInterrogation<'info> {
+    pub closer: Signer<'info>,
+
+    #[account(
+        seeds = [b"game_state"],
+        bump = game_state.bump,
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"interrogation",
+            game_state.key().as_ref(),
+            interrogation.round_id.to_le_bytes().as_ref(),
+        ],
+        bump = interrogation.bump,
+        has_one = payer @ SnitchError::Unauthorized,
+        close = payer,
+    )]
+    pub interrogation: Account<'info, Interrogation>,
+
+    /// CHECK: Original rent payer, checked against the interrogation
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+
+    /// Side pot (empty once the round is settled)
+    #[account(
+        mut,
+        seeds = [b"side_pot", interrogation.key().as_ref()],
+        bump,
+    )]
+    pub side_pot: Account<'info, TokenAccount>,
+
+    /// Bribe escrow (empty once the round is settled)
+    #[account(
+        mut,
+        seeds = [b"bribe_escrow", interrogation.key().as_ref()],
+        bump,
+    )]
+    pub bribe_escrow: Account<'info, TokenAccount>,
+
+    /// CHECK: Suspect chat, closed to whoever opened it if anyone posted
+    #[account(
+        mut,
+        seeds = [b"message_log", interrogation.key().as_ref()],
+        bump,
+    )]
+    pub message_log: UncheckedAccount<'info>,
+
+    /// CHECK: Payer of the message log, checked against it (only needed if the log exists)
+    #[account(mut)]
+    pub message_log_payer: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Token authority (PDA)
+    #[account(
+        seeds = [b"game_state"],
+        bump = game_state.bump,
+    )]
+    pub token_authority: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Accounts for closing a player's own profile
+#[derive(Accounts)]
+pub struct CloseProfile<'info> {
+    /// Player closing their profile (pays for the tombstone)
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// CHECK: Profile in any layout version; decoded and closed in the handler
+    #[account(
+        mut,
+        seeds = [b"player", player.key().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub player_profile: UncheckedAccount<'info>,
+
+    /// Record of the closed profile, restored if the profile is re-created
+    #[account(
+        init,
+        payer = player,
+        space = ProfileTombstone::SIZE,
+        seeds = [b"profile_tombstone", player.key().as_ref()],
+        bump,
+    )]
+    pub tombstone: Account<'info, ProfileTombstone>,
+
+    /// CHECK: Account that paid the profile's rent, checked against the profile
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn close_interrogation_handler(ctx: Context<CloseInterrogation>) -> Result<()> {
+    let interrogation = &ctx.accounts.interrogation;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        interrogation.outcome != Outcome::Pending,
+        SnitchError::RoundActive
+    );
+    require!(
+        interrogation.can_close(now),
+        SnitchError::RetentionPeriodActive
+    );
+
+    // Close the round's escrows, returning their rent to the same payer
+    let seeds = &[
+        b"game_state",
+        &[ctx.accounts.game_state.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    for escrow in [
+        ctx.accounts.side_pot.to_account_info(),
+        ctx.accounts.bribe_escrow.to_account_info(),
+    ] {
+        let close_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: escrow,
+                destination: ctx.accounts.payer.to_account_info(),
+                authority: ctx.accounts.token_authority.to_account_info(),
+            },
+            signer,
+        );
+        token::close_account(close_ctx)?;
+    }
+
+    let message_log = ctx.accounts.message_log.to_account_info();
+    if !message_log.data_is_empty() {
+        let log_payer = MessageLog::try_deserialize(&mut &message_log.try_borrow_data()?[..])?.payer;
+        let destination = ctx
+            .accounts
+            .message_log_payer
+            .as_ref()
+            .ok_or(SnitchError::Unauthorized)?;
+        require_keys_eq!(destination.key(), log_payer, SnitchError::Unauthorized);
+        close_unchecked(&message_log, &destination.to_account_info())?;
+    }
+
+    emit!(InterrogationClosed {
+        round_id: interrogation.round_id,
+        payer: interrogation.payer,
+        closed_by: ctx.accounts.closer.key(),
+        timestamp: now,
+    });
+
+    msg!("Interrogation {} closed, rent returned to {}", interrogation.round_id, interrogation.payer);
+
+    Ok(())
+}
+
+pub fn close_profile_handler(ctx: Context<CloseProfile>) -> Result<()> {
+    let info = ctx.accounts.player_profile.to_account_info();
+    let mut player_profile = PlayerProfile::try_deserialize_any_version(&info.try_borrow_data()?[..])?;
+    // Older profiles predate rent_payer; migrating fills it in
+    player_profile.migrate();
+    let now = Clock::get()?.unix_timestamp;
+
+    require_keys_eq!(player_profile.player, ctx.accounts.player.key(), SnitchError::Unauthorized);
+    require_keys_eq!(player_profile.rent_payer, ctx.accounts.rent_payer.key(), SnitchError::Unauthorized);
+    require!(player_profile.pending_rewards == 0, SnitchError::PendingRewardsUnclaimed);
+    require!(player_profile.current_round == 0, SnitchError::RoundActive);
+    require!(!player_profile.is_on_cooldown(now), SnitchError::PlayerOnCooldown);
+
+    // Keep the record aside so the profile can't be re-created with a clean one
+    *ctx.accounts.tombstone = ProfileTombstone::new(&player_profile, now);
+    close_unchecked(&info, &ctx.accounts.rent_payer.to_account_info())?;
+
+    emit!(ProfileClosed {
+        player: player_profile.player,
+        rent_payer: player_profile.rent_payer,
+        timestamp: now,
+    });
+
+    msg!("Profile of {} closed", player_profile.player);
+
+    Ok(())
+}
+
+/// Carry a closed profile's record into its re-created profile and return the
+/// tombstone's rent to the player. Does nothing if the player never closed a profile.
+pub fn restore_closed_profile<'info>(
+    profile: &mut PlayerProfile,
+    tombstone: &AccountInfo<'info>,
+    player: &AccountInfo<'info>,
+) -> Result<()> {
+    if tombstone.data_is_empty() {
+        return Ok(());
+    }
+    require_keys_eq!(*tombstone.owner, crate::ID, SnitchError::Unauthorized);
+    let record = ProfileTombstone::try_deserialize(&mut &tombstone.try_borrow_data()?[..])?;
+    require_keys_eq!(record.player, player.key(), SnitchError::Unauthorized);
+    record.restore(profile);
+    close_unchecked(tombstone, player)
+}
+
+/// Close a program account that was only decoded in the handler, sending its rent to `destination`
+fn close_unchecked<'info>(account: &AccountInfo<'info>, destination: &AccountInfo<'info>) -> Result<()> {
+    destination.add_lamports(account.lamports())?;
+    account.sub_lamports(account.lamports())?;
+    account.assign(&System::id());
+    account.realloc(0, false)?;
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer, Token, TokenAccount};
 use solana_program::clock::Clock;
 use solana_program::hash::hashv;
 
@@ -7,10 +8,11 @@ use crate::SnitchError;
 use crate::ChoiceCommitted;
 
 #[derive(Accounts)]
-#[instruction(commitment: [u8; 32])]
+#[instruction(commitment: [u8; 32], side_wager: u64)]
 pub struct CommitChoice<'info> {
+    /// Player wallet or their registered delegate
     #[account(mut)]
-    pub player: Signer<'info>,
+    pub signer: Signer<'info>,
     
     #[account(
         seeds = [b"game_state"],
@@ -26,16 +28,34 @@ pub struct CommitChoice<'info> {
     
     #[account(
         mut,
-        seeds = [b"player", player.key().as_ref()],
+        seeds = [b"player", player_profile.player.as_ref()],
         bump = player_profile.bump,
-        constraint = player_profile.player == player.key() @ SnitchError::Unauthorized,
     )]
     pub player_profile: Account<'info, PlayerProfile>,
+    
+    /// Player's token account (funds the side wager; a delegate needs an SPL approval)
+    #[account(
+        mut,
+        constraint = player_token.owner == player_profile.player @ SnitchError::Unauthorized,
+        constraint = player_token.mint == game_state.token_mint @ SnitchError::Unauthorized,
+    )]
+    pub player_token: Account<'info, TokenAccount>,
+    
+    /// Side pot for this round
+    #[account(
+        mut,
+        seeds = [b"side_pot", interrogation.key().as_ref()],
+        bump,
+    )]
+    pub side_pot: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
 }
 
 pub fn handler(
     ctx: Context<CommitChoice>,
     commitment: [u8; 32],
+    side_wager: u64,
 ) -> Result<()> {
     let game_state = &ctx.accounts.game_state;
     let interrogation = &mut ctx.accounts.interrogation;
@@ -56,8 +76,14 @@ pub fn handler(
         SnitchError::CommitPhaseEnded
     );
     
+    // Validate signer is the player or their active delegate
+    let player_key = ctx.accounts.player_profile.player;
+    ctx.accounts.player_profile.validate_player_or_delegate(
+        &ctx.accounts.signer.key(),
+        now,
+    )?;
+    
     // Validate player is in this round
-    let player_key = ctx.accounts.player.key();
     require!(
         interrogation.is_player_in_round(&player_key),
         SnitchError::PlayerNotInInterrogation
@@ -80,6 +106,25 @@ pub fn handler(
         interrogation.commitment_b = Some(commitment);
     }
     
+    // Double down: move the side wager into the round's side pot
+    if side_wager > 0 {
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.player_token.to_account_info(),
+                to: ctx.accounts.side_pot.to_account_info(),
+                authority: ctx.accounts.signer.to_account_info(),
+            },
+        );
+        token::transfer(transfer_ctx, side_wager)?;
+        
+        if is_player_a {
+            interrogation.side_pot_a = side_wager;
+        } else {
+            interrogation.side_pot_b = side_wager;
+        }
+    }
+    
     // Update phase if both committed
     if interrogation.both_committed() {
         // Optionally move to reveal phase early if both committed
@@ -91,6 +136,10 @@ pub fn handler(
         round_id: interrogation.round_id,
         player: player_key,
         commitment,
+        side_wager,
+        side_pot_a: interrogation.side_pot_a,
+        side_pot_b: interrogation.side_pot_b,
+        revision: 0,
         timestamp: now,
     });
     
@@ -115,8 +164,7 @@ pub fn verify_commitment(
         &round_bytes,
     ];
     
-    let computed_hash = hashv(&hash_input.iter().map(|b| *b).collect::<Vec<_>>()
-    );
+    let computed_hash = hashv(&hash_input);
     
     commitment == computed_hash.to_bytes()
 }
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use solana_program::clock::Clock;
+
+use crate::state::*;
+use crate::instructions::close::restore_closed_profile;
+use crate::SnitchError;
+use crate::{DelegateSet, DelegateRevoked};
+
+// ==================== Session Delegates ====================
+
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey, expires_at: i64)]
+pub struct SetDelegate<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    /// Player profile (init if needed)
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = PlayerProfile::SIZE,
+        seeds = [b"player", player.key().as_ref()],
+        bump,
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    /// CHECK: Tombstone of a previously closed profile, if any (restored and closed)
+    #[account(
+        mut,
+        seeds = [b"profile_tombstone", player.key().as_ref()],
+        bump,
+    )]
+    pub tombstone: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_delegate_handler(
+    ctx: Context<SetDelegate>,
+    delegate: Pubkey,
+    expires_at: i64,
+) -> Result<()> {
+    let player_key = ctx.accounts.player.key();
+    let player_profile = &mut ctx.accounts.player_profile;
+    let now = Clock::get()?.unix_timestamp;
+    
+    require!(delegate != player_key, SnitchError::InvalidDelegate);
+    require!(expires_at > now, SnitchError::InvalidDelegate);
+    
+    player_profile.player = player_key;
+    player_profile.bump = ctx.bumps.player_profile;
+    restore_closed_profile(
+        player_profile,
+        &ctx.accounts.tombstone.to_account_info(),
+        &ctx.accounts.player.to_account_info(),
+    )?;
+    player_profile.migrate();
+    player_profile.delegate = Some(delegate);
+    player_profile.delegate_expires_at = expires_at;
+    
+    emit!(DelegateSet {
+        player: player_key,
+        delegate,
+        expires_at,
+        timestamp: now,
+    });
+    
+    msg!("Player {} delegated commit/reveal to {} until {}", 
+        player_key, delegate, expires_at);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeDelegate<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"player", player.key().as_ref()],
+        bump = player_profile.bump,
+        constraint = player_profile.player == player.key() @ SnitchError::Unauthorized,
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+}
+
+pub fn revoke_delegate_handler(ctx: Context<RevokeDelegate>) -> Result<()> {
+    let player_profile = &mut ctx.accounts.player_profile;
+    let now = Clock::get()?.unix_timestamp;
+    
+    let delegate = player_profile.delegate
+        .ok_or(SnitchError::InvalidDelegate)?;
+    
+    player_profile.delegate = None;
+    player_profile.delegate_expires_at = 0;
+    
+    emit!(DelegateRevoked {
+        player: player_profile.player,
+        delegate,
+        timestamp: now,
+    });
+    
+    msg!("Player {} revoked delegate {}", player_profile.player, delegate);
+    
+    Ok(())
+}
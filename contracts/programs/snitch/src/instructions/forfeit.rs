@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use solana_program::clock::Clock;
+
+use crate::state::*;
+use crate::PlayerForfeited;
+use crate::instructions::resolve_round::{self, ResolveRound};
+
+/// Plead guilty: the forfeiting player signs as `resolver` and the round
+/// resolves immediately with them cooperating. An opponent who already
+/// revealed keeps their choice; one who has not is treated as snitching.
+/// Rewards, penalties and side pot winnings are scaled by
+/// `forfeit_penalty_bps`. A forfeit is not a real decision, so it leaves both
+/// players' history and stats untouched.
+pub fn handler(ctx: Context<ResolveRound>) -> Result<()> {
+    let interrogation = &mut ctx.accounts.interrogation;
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    
+    // Only a player still holding an unrevealed choice can plead guilty
+    let player_key = ctx.accounts.resolver.key();
+    interrogation.validate_forfeit(&player_key, now)?;
+    
+    // Forfeiter is the cooperator; a silent opponent counts as the snitch
+    if interrogation.player_a == player_key {
+        interrogation.choice_a.get_or_insert(Choice::Cooperate);
+        interrogation.choice_b.get_or_insert(Choice::Snitch);
+    } else {
+        interrogation.choice_a.get_or_insert(Choice::Snitch);
+        interrogation.choice_b.get_or_insert(Choice::Cooperate);
+    }
+    interrogation.forfeited_by = Some(player_key);
+    
+    emit!(PlayerForfeited {
+        round_id: interrogation.round_id,
+        player: player_key,
+        timestamp: now,
+    });
+    
+    msg!("Player {} forfeited round {}", player_key, interrogation.round_id);
+    
+    resolve_round::handler(ctx)
+}
@@ -0,0 +1,472 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Transfer, Token, TokenAccount, Mint};
+use solana_program::clock::Clock;
+
+use crate::state::*;
+use crate::SnitchError;
+use crate::instructions::admin::AdminOnly;
+use crate::{
+    ConfigProposed, GovernanceProposalCreated, VoteCast, VoteWithdrawn,
+    GovernanceProposalExecuted, GovernanceProposalClosed,
+};
+
+// ==================== Governance Instructions ====================
+
+#[derive(Accounts)]
+pub struct CreateGovernanceProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"game_state"],
+        bump = game_state.bump,
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    /// Proposer's token account (must meet the selection balance threshold)
+    #[account(
+        constraint = proposer_token.owner == proposer.key() @ SnitchError::Unauthorized,
+        constraint = proposer_token.mint == game_state.token_mint @ SnitchError::Unauthorized,
+    )]
+    pub proposer_token: Account<'info, TokenAccount>,
+    
+    #[account(
+        init,
+        payer = proposer,
+        space = GovernanceProposal::SIZE,
+        seeds = [
+            b"gov_proposal",
+            game_state.key().as_ref(),
+            game_state.governance.proposal_count.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+    
+    /// The SNITCH token mint
+    #[account(
+        address = game_state.token_mint @ SnitchError::Unauthorized,
+    )]
+    pub token_mint: Account<'info, Mint>,
+    
+    /// Escrow holding the tokens locked by voters
+    #[account(
+        init,
+        payer = proposer,
+        seeds = [b"vote_escrow", proposal.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = game_state,
+    )]
+    pub vote_escrow: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CastVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    
+    #[account(
+        seeds = [b"game_state"],
+        bump = game_state.bump,
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"gov_proposal",
+            game_state.key().as_ref(),
+            proposal.id.to_le_bytes().as_ref(),
+        ],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+    
+    /// One vote per holder per proposal
+    #[account(
+        init,
+        payer = voter,
+        space = VoteRecord::SIZE,
+        seeds = [b"vote", proposal.key().as_ref(), voter.key().as_ref()],
+        bump,
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    
+    /// Voter's token account
+    #[account(
+        mut,
+        constraint = voter_token.owner == voter.key() @ SnitchError::Unauthorized,
+        constraint = voter_token.mint == game_state.token_mint @ SnitchError::Unauthorized,
+    )]
+    pub voter_token: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"vote_escrow", proposal.key().as_ref()],
+        bump,
+    )]
+    pub vote_escrow: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    
+    #[account(
+        seeds = [b"game_state"],
+        bump = game_state.bump,
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"gov_proposal",
+            game_state.key().as_ref(),
+            proposal.id.to_le_bytes().as_ref(),
+        ],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+    
+    #[account(
+        mut,
+        seeds = [b"vote", proposal.key().as_ref(), voter.key().as_ref()],
+        bump = vote_record.bump,
+        close = voter,
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    
+    /// Voter's token account
+    #[account(
+        mut,
+        constraint = voter_token.owner == voter.key() @ SnitchError::Unauthorized,
+        constraint = voter_token.mint == game_state.token_mint @ SnitchError::Unauthorized,
+    )]
+    pub voter_token: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"vote_escrow", proposal.key().as_ref()],
+        bump,
+    )]
+    pub vote_escrow: Account<'info, TokenAccount>,
+    
+    /// CHECK: Token authority (PDA)
+    #[account(
+        seeds = [b"game_state"],
+        bump = game_state.bump,
+    )]
+    pub token_authority: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteGovernanceProposal<'info> {
+    pub executor: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"game_state"],
+        bump = game_state.bump,
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"gov_proposal",
+            game_state.key().as_ref(),
+            proposal.id.to_le_bytes().as_ref(),
+        ],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+}
+
+/// Accounts for closing a settled proposal once every vote has been withdrawn
+/// (permissionless, rent goes to the proposer)
+#[derive(Accounts)]
+pub struct CloseGovernanceProposal<'info> {
+    pub closer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"game_state"],
+        bump = game_state.bump,
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"gov_proposal",
+            game_state.key().as_ref(),
+            proposal.id.to_le_bytes().as_ref(),
+        ],
+        bump = proposal.bump,
+        has_one = proposer @ SnitchError::Unauthorized,
+        close = proposer,
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+    
+    /// CHECK: Proposer, checked against the proposal
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    
+    /// Vote escrow (empty once every vote is withdrawn)
+    #[account(
+        mut,
+        seeds = [b"vote_escrow", proposal.key().as_ref()],
+        bump,
+    )]
+    pub vote_escrow: Account<'info, TokenAccount>,
+    
+    /// CHECK: Token authority (PDA)
+    #[account(
+        seeds = [b"game_state"],
+        bump = game_state.bump,
+    )]
+    pub token_authority: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn create_governance_proposal_handler(
+    ctx: Context<CreateGovernanceProposal>,
+    config: GameConfig,
+) -> Result<()> {
+    let game_state = &mut ctx.accounts.game_state;
+    let proposal = &mut ctx.accounts.proposal;
+    let now = Clock::get()?.unix_timestamp;
+    
+    config.validate()?;
+    
+    // Same bar as being eligible to play keeps proposal spam out
+    require!(
+        ctx.accounts.proposer_token.amount >= game_state.config.min_balance_threshold,
+        SnitchError::InsufficientBalance
+    );
+    
+    proposal.id = game_state.governance.proposal_count;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.config = config.clone();
+    proposal.voting_starts_at = now;
+    proposal.voting_ends_at = now
+        .checked_add(game_state.governance.voting_period)
+        .ok_or(SnitchError::Overflow)?;
+    proposal.votes_for = 0;
+    proposal.votes_against = 0;
+    proposal.executed = false;
+    proposal.bump = ctx.bumps.proposal;
+    proposal.open_votes = 0;
+    
+    game_state.governance.proposal_count = game_state.governance.proposal_count
+        .checked_add(1)
+        .ok_or(SnitchError::Overflow)?;
+    
+    emit!(GovernanceProposalCreated {
+        proposal_id: proposal.id,
+        proposer: proposal.proposer,
+        config,
+        voting_ends_at: proposal.voting_ends_at,
+        timestamp: now,
+    });
+    
+    msg!("Governance proposal {} created, voting until {}", proposal.id, proposal.voting_ends_at);
+    
+    Ok(())
+}
+
+pub fn cast_vote_handler(
+    ctx: Context<CastVote>,
+    amount: u64,
+    support: bool,
+) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let now = Clock::get()?.unix_timestamp;
+    
+    require!(amount > 0, SnitchError::InvalidAmount);
+    require!(proposal.is_voting_open(now), SnitchError::VotingClosed);
+    
+    // Lock the voting weight until the window closes
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.voter_token.to_account_info(),
+            to: ctx.accounts.vote_escrow.to_account_info(),
+            authority: ctx.accounts.voter.to_account_info(),
+        },
+    );
+    token::transfer(transfer_ctx, amount)?;
+    
+    if support {
+        proposal.votes_for = proposal.votes_for
+            .checked_add(amount)
+            .ok_or(SnitchError::Overflow)?;
+    } else {
+        proposal.votes_against = proposal.votes_against
+            .checked_add(amount)
+            .ok_or(SnitchError::Overflow)?;
+    }
+    proposal.open_votes = proposal.open_votes
+        .checked_add(1)
+        .ok_or(SnitchError::Overflow)?;
+    
+    let vote_record = &mut ctx.accounts.vote_record;
+    vote_record.proposal = proposal.key();
+    vote_record.voter = ctx.accounts.voter.key();
+    vote_record.amount = amount;
+    vote_record.support = support;
+    vote_record.bump = ctx.bumps.vote_record;
+    
+    emit!(VoteCast {
+        proposal_id: proposal.id,
+        voter: vote_record.voter,
+        amount,
+        support,
+        votes_for: proposal.votes_for,
+        votes_against: proposal.votes_against,
+        timestamp: now,
+    });
+    
+    Ok(())
+}
+
+pub fn withdraw_vote_handler(ctx: Context<WithdrawVote>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let vote_record = &ctx.accounts.vote_record;
+    let now = Clock::get()?.unix_timestamp;
+    
+    require!(now >= proposal.voting_ends_at, SnitchError::VotingActive);
+    
+    proposal.open_votes = proposal.open_votes.saturating_sub(1);
+    
+    let seeds = &[
+        b"game_state",
+        &[ctx.accounts.game_state.bump],
+    ];
+    let signer = &[&seeds[..]];
+    
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.vote_escrow.to_account_info(),
+            to: ctx.accounts.voter_token.to_account_info(),
+            authority: ctx.accounts.token_authority.to_account_info(),
+        },
+        signer,
+    );
+    token::transfer(transfer_ctx, vote_record.amount)?;
+    
+    emit!(VoteWithdrawn {
+        proposal_id: proposal.id,
+        voter: vote_record.voter,
+        amount: vote_record.amount,
+        timestamp: now,
+    });
+    
+    Ok(())
+}
+
+/// Permissionless once voting has closed, until `Governance::EXECUTION_WINDOW`
+/// has passed. A passing proposal is staged through the same timelocked path as
+/// `update_config`, so the config manager can still cancel it before it applies;
+/// it fails while another config is pending.
+pub fn execute_governance_proposal_handler(ctx: Context<ExecuteGovernanceProposal>) -> Result<()> {
+    let game_state = &mut ctx.accounts.game_state;
+    let proposal = &mut ctx.accounts.proposal;
+    let now = Clock::get()?.unix_timestamp;
+    
+    require!(!proposal.executed, SnitchError::ProposalAlreadyExecuted);
+    require!(now >= proposal.voting_ends_at, SnitchError::VotingActive);
+    require!(!proposal.is_expired(now), SnitchError::ProposalExpired);
+    require!(
+        proposal.has_passed(game_state.governance.quorum),
+        SnitchError::ProposalRejected
+    );
+    
+    proposal.executed = true;
+    
+    let activates_at = game_state.propose_config(proposal.config.clone(), now)?;
+    
+    emit!(ConfigProposed {
+        proposed_by: proposal.key(),
+        config: proposal.config.clone(),
+        activates_at,
+        timestamp: now,
+    });
+    
+    emit!(GovernanceProposalExecuted {
+        proposal_id: proposal.id,
+        votes_for: proposal.votes_for,
+        votes_against: proposal.votes_against,
+        executed_by: ctx.accounts.executor.key(),
+        timestamp: now,
+    });
+    
+    msg!("Governance proposal {} passed, config applies at {}", proposal.id, activates_at);
+    
+    Ok(())
+}
+
+pub fn set_governance_handler(
+    ctx: Context<AdminOnly>,
+    voting_period: i64,
+    quorum: u64,
+) -> Result<()> {
+    ctx.accounts.game_state.governance.set(voting_period, quorum)?;
+    
+    msg!("Governance set: {}s voting window, quorum {}", voting_period, quorum);
+    
+    Ok(())
+}
+
+pub fn close_governance_proposal_handler(ctx: Context<CloseGovernanceProposal>) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    let now = Clock::get()?.unix_timestamp;
+    
+    require!(now >= proposal.voting_ends_at, SnitchError::VotingActive);
+    require!(
+        proposal.is_settled(ctx.accounts.game_state.governance.quorum, now),
+        SnitchError::ProposalExecutable
+    );
+    require!(proposal.open_votes == 0, SnitchError::VotesOutstanding);
+    
+    let seeds = &[
+        b"game_state",
+        &[ctx.accounts.game_state.bump],
+    ];
+    let signer = &[&seeds[..]];
+    
+    let close_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.vote_escrow.to_account_info(),
+            destination: ctx.accounts.proposer.to_account_info(),
+            authority: ctx.accounts.token_authority.to_account_info(),
+        },
+        signer,
+    );
+    token::close_account(close_ctx)?;
+    
+    emit!(GovernanceProposalClosed {
+        proposal_id: proposal.id,
+        executed: proposal.executed,
+        closed_by: ctx.accounts.closer.key(),
+        timestamp: now,
+    });
+    
+    msg!("Governance proposal {} closed", proposal.id);
+    
+    Ok(())
+}
@@ -1,9 +1,11 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Transfer, Token, TokenAccount, Mint};
+use anchor_spl::token::{Token, TokenAccount, Mint};
 use solana_program::clock::Clock;
 
 use crate::state::*;
+use crate::instructions::close::restore_closed_profile;
 use crate::SnitchError;
+use crate::utils::selection_seed;
 use crate::{RoundStarted, PlayerSelected};
 
 #[derive(Accounts)]
@@ -52,6 +54,7 @@ pub fn handler(
     let game_state = &mut ctx.accounts.game_state;
     let clock = Clock::get()?;
     
+    game_state.version = GameState::CURRENT_VERSION;
     game_state.authority = ctx.accounts.authority.key();
     game_state.token_mint = ctx.accounts.token_mint.key();
     game_state.current_round = 0;
@@ -64,6 +67,19 @@ pub fn handler(
     game_state.total_rewards_distributed = 0;
     game_state.bump = ctx.bumps.game_state;
     game_state.reserved = [0; 32];
+    game_state.pending_authority = None;
+    game_state.roles = Roles::default();
+    game_state.pending_config = None;
+    game_state.pending_config_activates_at = 0;
+    game_state.config_timelock = GameState::DEFAULT_CONFIG_TIMELOCK;
+    game_state.multisig = Multisig::default();
+    game_state.governance = Governance {
+        voting_period: Governance::DEFAULT_VOTING_PERIOD,
+        quorum: Governance::default_quorum(&game_state.config),
+        ..Governance::default()
+    };
+    game_state.leaderboard = None;
+    game_state.round_history = None;
     
     msg!("Game initialized at {}", clock.unix_timestamp);
     msg!("Authority: {}", game_state.authority);
@@ -119,6 +135,50 @@ pub struct SelectPlayers<'info> {
     )]
     pub player_b_profile: Account<'info, PlayerProfile>,
     
+    /// CHECK: Player A's wallet (receives a restored tombstone's rent)
+    #[account(
+        mut,
+        address = player_a_token.owner @ SnitchError::Unauthorized,
+    )]
+    pub player_a: UncheckedAccount<'info>,
+    
+    /// CHECK: Player B's wallet (receives a restored tombstone's rent)
+    #[account(
+        mut,
+        address = player_b_token.owner @ SnitchError::Unauthorized,
+    )]
+    pub player_b: UncheckedAccount<'info>,
+    
+    /// CHECK: Tombstone of player A's closed profile, if any (restored and closed)
+    #[account(
+        mut,
+        seeds = [b"profile_tombstone", player_a_token.owner.as_ref()],
+        bump,
+    )]
+    pub player_a_tombstone: UncheckedAccount<'info>,
+    
+    /// CHECK: Tombstone of player B's closed profile, if any (restored and closed)
+    #[account(
+        mut,
+        seeds = [b"profile_tombstone", player_b_token.owner.as_ref()],
+        bump,
+    )]
+    pub player_b_tombstone: UncheckedAccount<'info>,
+    
+    /// Head-to-head history for this pair (init on first meeting)
+    #[account(
+        init_if_needed,
+        payer = selector,
+        space = PairHistory::SIZE,
+        seeds = [
+            b"pair_history",
+            PairHistory::lo(&player_a_token.owner, &player_b_token.owner).as_ref(),
+            PairHistory::hi(&player_a_token.owner, &player_b_token.owner).as_ref(),
+        ],
+        bump,
+    )]
+    pub pair_history: Account<'info, PairHistory>,
+    
     /// New interrogation round account
     #[account(
         init,
@@ -133,6 +193,35 @@ pub struct SelectPlayers<'info> {
     )]
     pub interrogation: Account<'info, Interrogation>,
     
+    /// The SNITCH token mint
+    #[account(
+        address = game_state.token_mint @ SnitchError::Unauthorized,
+    )]
+    pub token_mint: Account<'info, Mint>,
+    
+    /// Side pot holding this round's double-down wagers
+    #[account(
+        init,
+        payer = selector,
+        seeds = [b"side_pot", interrogation.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = game_state,
+    )]
+    pub side_pot: Account<'info, TokenAccount>,
+    
+    /// Escrow holding this round's bribe offers
+    #[account(
+        init,
+        payer = selector,
+        seeds = [b"bribe_escrow", interrogation.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = game_state,
+    )]
+    pub bribe_escrow: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
@@ -170,6 +259,18 @@ pub fn handler(
     let player_a = ctx.accounts.player_a_token.owner;
     let player_b = ctx.accounts.player_b_token.owner;
     
+    // A re-created profile gets its closed record back
+    restore_closed_profile(
+        &mut ctx.accounts.player_a_profile,
+        &ctx.accounts.player_a_tombstone.to_account_info(),
+        &ctx.accounts.player_a.to_account_info(),
+    )?;
+    restore_closed_profile(
+        &mut ctx.accounts.player_b_profile,
+        &ctx.accounts.player_b_tombstone.to_account_info(),
+        &ctx.accounts.player_b.to_account_info(),
+    )?;
+    
     // Check cooldowns
     require!(
         !ctx.accounts.player_a_profile.is_on_cooldown(now),
@@ -216,12 +317,41 @@ pub fn handler(
     interrogation.player_a_reward = 0;
     interrogation.player_b_reward = 0;
     interrogation.amount_burned = 0;
+    interrogation.lawyer_fee_a = 0;
+    interrogation.lawyer_fee_b = 0;
+    interrogation.side_pot_a = 0;
+    interrogation.side_pot_b = 0;
+    interrogation.bribe_a = 0;
+    interrogation.bribe_b = 0;
+    interrogation.pledge_a = None;
+    interrogation.pledge_b = None;
+    interrogation.autopilot_a = false;
+    interrogation.autopilot_b = false;
+    interrogation.revisions_a = 0;
+    interrogation.revisions_b = 0;
+    interrogation.forfeited_by = None;
+    interrogation.payoff_config = PayoffConfig::from(&game_state.config);
+    interrogation.pnl_a = RoundPnl::default();
+    interrogation.pnl_b = RoundPnl::default();
     interrogation.rewards_distributed = false;
+    interrogation.resolved_at = 0;
+    interrogation.payer = ctx.accounts.selector.key();
     interrogation.bump = ctx.bumps.interrogation;
+    interrogation.selection_seed = selection_seed(&random_seed, clock.slot);
+    interrogation.previous_selected_at_a = ctx.accounts.player_a_profile.last_selected_at;
+    interrogation.previous_selected_at_b = ctx.accounts.player_b_profile.last_selected_at;
     
     // Update game state
     game_state.active_interrogation = Some(interrogation.key());
     
+    // First meeting of this pair
+    let pair_history = &mut ctx.accounts.pair_history;
+    if pair_history.player_lo == Pubkey::default() {
+        pair_history.player_lo = *interrogation.player_lo();
+        pair_history.player_hi = *interrogation.player_hi();
+        pair_history.bump = ctx.bumps.pair_history;
+    }
+    
     // Update player profiles
     let player_a_profile = &mut ctx.accounts.player_a_profile;
     player_a_profile.player = player_a;
@@ -231,6 +361,10 @@ pub fn handler(
     player_a_profile.current_round = round_id;
     player_a_profile.set_cooldown(config.cooldown_period, now);
     player_a_profile.bump = ctx.bumps.player_a_profile;
+    if player_a_profile.version == 0 {
+        player_a_profile.rent_payer = ctx.accounts.selector.key();
+    }
+    player_a_profile.migrate();
     
     let player_b_profile = &mut ctx.accounts.player_b_profile;
     player_b_profile.player = player_b;
@@ -240,12 +374,18 @@ pub fn handler(
     player_b_profile.current_round = round_id;
     player_b_profile.set_cooldown(config.cooldown_period, now);
     player_b_profile.bump = ctx.bumps.player_b_profile;
+    if player_b_profile.version == 0 {
+        player_b_profile.rent_payer = ctx.accounts.selector.key();
+    }
+    player_b_profile.migrate();
     
     // Emit events
     emit!(RoundStarted {
         round_id,
         player_a,
         player_b,
+        trust_score_a: ctx.accounts.player_a_profile.current_trust_score(now),
+        trust_score_b: ctx.accounts.player_b_profile.current_trust_score(now),
         timestamp: now,
     });
     
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::SnitchError;
+
+#[derive(Accounts)]
+pub struct InitializeLeaderboard<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"game_state"],
+        bump = game_state.bump,
+        constraint = game_state.authority == admin.key() @ SnitchError::Unauthorized,
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = Leaderboard::SIZE,
+        seeds = [b"leaderboard", game_state.key().as_ref()],
+        bump,
+    )]
+    pub leaderboard: AccountLoader<'info, Leaderboard>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_leaderboard_handler(ctx: Context<InitializeLeaderboard>) -> Result<()> {
+    let mut leaderboard = ctx.accounts.leaderboard.load_init()?;
+    
+    leaderboard.game_state = ctx.accounts.game_state.key();
+    leaderboard.bump = ctx.bumps.leaderboard;
+    
+    // From now on every resolution must update it
+    ctx.accounts.game_state.leaderboard = Some(ctx.accounts.leaderboard.key());
+    
+    msg!("Leaderboard initialized: top {} per board", Leaderboard::MAX_ENTRIES);
+    
+    Ok(())
+}
@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use solana_program::clock::Clock;
+
+use crate::state::*;
+use crate::AccountMigrated;
+
+/// Accounts for migrating a player profile to the current layout.
+/// Permissionless: migration is deterministic, the payer only funds the extra rent.
+#[derive(Accounts)]
+pub struct MigrateProfile<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Player whose profile is migrated (only used for the PDA seeds)
+    pub player: UncheckedAccount<'info>,
+
+    /// CHECK: Raw profile in any layout version; decoded by `PlayerProfile::try_deserialize_any_version`
+    #[account(
+        mut,
+        seeds = [b"player", player.key().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub player_profile: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for migrating the game state to the current layout
+#[derive(Accounts)]
+pub struct MigrateGameState<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Raw game state in any layout version; decoded by `GameState::try_deserialize_any_version`
+    #[account(
+        mut,
+        seeds = [b"game_state"],
+        bump,
+        owner = crate::ID,
+    )]
+    pub game_state: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_profile_handler(ctx: Context<MigrateProfile>) -> Result<()> {
+    let info = ctx.accounts.player_profile.to_account_info();
+    // Decode with the account's own layout before growing it
+    let mut profile = PlayerProfile::try_deserialize_any_version(&info.try_borrow_data()?[..])?;
+    let from_version = profile.version;
+    if !profile.migrate() {
+        msg!("Profile already at version {}", from_version);
+        return Ok(());
+    }
+
+    realloc_to(
+        &info,
+        PlayerProfile::SIZE,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )?;
+    profile.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    emit!(AccountMigrated {
+        account: info.key(),
+        from_version,
+        to_version: profile.version,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Profile migrated from version {} to {}", from_version, profile.version);
+
+    Ok(())
+}
+
+pub fn migrate_game_state_handler(ctx: Context<MigrateGameState>) -> Result<()> {
+    let info = ctx.accounts.game_state.to_account_info();
+    // Decode with the account's own layout before growing it
+    let mut game_state = GameState::try_deserialize_any_version(&info.try_borrow_data()?[..])?;
+    let from_version = game_state.version;
+    if !game_state.migrate() {
+        msg!("Game state already at version {}", from_version);
+        return Ok(());
+    }
+
+    realloc_to(
+        &info,
+        GameState::SIZE,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )?;
+    game_state.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    emit!(AccountMigrated {
+        account: info.key(),
+        from_version,
+        to_version: game_state.version,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Game state migrated from version {} to {}", from_version, game_state.version);
+
+    Ok(())
+}
+
+/// Grow an account to `size`, topping up rent from the payer.
+/// Accounts already at least `size` bytes are left alone.
+fn realloc_to<'info>(
+    account: &AccountInfo<'info>,
+    size: usize,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    if account.data_len() >= size {
+        return Ok(());
+    }
+
+    let rent_due = Rent::get()?
+        .minimum_balance(size)
+        .saturating_sub(account.lamports());
+    if rent_due > 0 {
+        let transfer_ctx = CpiContext::new(
+            system_program.to_account_info(),
+            Transfer {
+                from: payer.to_account_info(),
+                to: account.clone(),
+            },
+        );
+        system_program::transfer(transfer_ctx, rent_due)?;
+    }
+
+    account.realloc(size, true)?;
+    Ok(())
+}
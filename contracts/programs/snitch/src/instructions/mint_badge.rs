@@ -0,0 +1,155 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::metadata::{
+    self,
+    mpl_token_metadata::types::DataV2,
+    CreateMetadataAccountsV3,
+    Metadata,
+};
+use anchor_spl::token::{self, FreezeAccount, Mint, MintTo, Token, TokenAccount};
+use solana_program::clock::Clock;
+
+use crate::state::*;
+use crate::SnitchError;
+use crate::BadgeMinted;
+
+#[derive(Accounts)]
+#[instruction(badge: Badge)]
+pub struct MintBadge<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    #[account(
+        seeds = [b"game_state"],
+        bump = game_state.bump,
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        seeds = [b"player", player.key().as_ref()],
+        bump = player_profile.bump,
+        constraint = player_profile.player == player.key() @ SnitchError::Unauthorized,
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    /// Badge mint, one per player per badge (supply 1, frozen = soulbound)
+    #[account(
+        init,
+        payer = player,
+        seeds = [b"badge", player.key().as_ref(), &[badge as u8]],
+        bump,
+        mint::decimals = 0,
+        mint::authority = game_state,
+        mint::freeze_authority = game_state,
+    )]
+    pub badge_mint: Account<'info, Mint>,
+    
+    /// Player's badge token account
+    #[account(
+        init,
+        payer = player,
+        associated_token::mint = badge_mint,
+        associated_token::authority = player,
+    )]
+    pub badge_token: Account<'info, TokenAccount>,
+    
+    /// CHECK: Metaplex metadata PDA, created by the metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), badge_mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub metadata: UncheckedAccount<'info>,
+    
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<MintBadge>,
+    badge: Badge,
+) -> Result<()> {
+    let player_profile = &mut ctx.accounts.player_profile;
+    let now = Clock::get()?.unix_timestamp;
+    
+    require!(player_profile.has_earned_badge(badge), SnitchError::BadgeNotEarned);
+    require!(!player_profile.has_minted_badge(badge), SnitchError::BadgeAlreadyMinted);
+    
+    let seeds = &[
+        b"game_state",
+        &[ctx.accounts.game_state.bump],
+    ];
+    let signer = &[&seeds[..]];
+    
+    // Attach Metaplex metadata so wallets can display the badge
+    let metadata_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_metadata_program.to_account_info(),
+        CreateMetadataAccountsV3 {
+            metadata: ctx.accounts.metadata.to_account_info(),
+            mint: ctx.accounts.badge_mint.to_account_info(),
+            mint_authority: ctx.accounts.game_state.to_account_info(),
+            payer: ctx.accounts.player.to_account_info(),
+            update_authority: ctx.accounts.game_state.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+        },
+        signer,
+    );
+    metadata::create_metadata_accounts_v3(
+        metadata_ctx,
+        DataV2 {
+            name: badge.name().to_string(),
+            symbol: Badge::SYMBOL.to_string(),
+            uri: String::new(),
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        },
+        false, // is_mutable
+        true,  // update_authority_is_signer
+        None,
+    )?;
+    
+    // Mint the single badge token to the player
+    let mint_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        MintTo {
+            mint: ctx.accounts.badge_mint.to_account_info(),
+            to: ctx.accounts.badge_token.to_account_info(),
+            authority: ctx.accounts.game_state.to_account_info(),
+        },
+        signer,
+    );
+    token::mint_to(mint_ctx, 1)?;
+    
+    // Freeze the holding so the badge can never be transferred
+    let freeze_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        FreezeAccount {
+            account: ctx.accounts.badge_token.to_account_info(),
+            mint: ctx.accounts.badge_mint.to_account_info(),
+            authority: ctx.accounts.game_state.to_account_info(),
+        },
+        signer,
+    );
+    token::freeze_account(freeze_ctx)?;
+    
+    player_profile.mark_badge_minted(badge);
+    
+    emit!(BadgeMinted {
+        player: ctx.accounts.player.key(),
+        badge,
+        mint: ctx.accounts.badge_mint.key(),
+        timestamp: now,
+    });
+    
+    msg!("Player {} minted badge {:?}", ctx.accounts.player.key(), badge);
+    
+    Ok(())
+}
@@ -1,13 +1,44 @@
+#![allow(ambiguous_glob_reexports)]
+
 pub mod initialize;
+pub mod pledge;
 pub mod commit_choice;
+pub mod update_commitment;
 pub mod reveal_choice;
+pub mod offer_bribe;
+pub mod post_message;
 pub mod resolve_round;
+pub mod forfeit;
 pub mod claim_rewards;
+pub mod mint_badge;
+pub mod delegate;
+pub mod set_strategy;
 pub mod admin;
+pub mod multisig;
+pub mod governance;
+pub mod leaderboard;
+pub mod round_history;
+pub mod cancel_round;
+pub mod migrate;
+pub mod close;
 
 pub use initialize::*;
+pub use pledge::*;
 pub use commit_choice::*;
+pub use update_commitment::*;
 pub use reveal_choice::*;
+pub use offer_bribe::*;
+pub use post_message::*;
 pub use resolve_round::*;
 pub use claim_rewards::*;
+pub use mint_badge::*;
+pub use delegate::*;
+pub use set_strategy::*;
 pub use admin::*;
+pub use multisig::*;
+pub use governance::*;
+pub use leaderboard::*;
+pub use round_history::*;
+pub use cancel_round::*;
+pub use migrate::*;
+pub use close::*;
@@ -0,0 +1,337 @@
+use anchor_lang::prelude::*;
+use solana_program::clock::Clock;
+
+use crate::state::*;
+use crate::SnitchError;
+use crate::instructions::admin::AdminOnly;
+use crate::{
+    AuthorityProposed, ConfigCancelled, ConfigProposed, MultisigUpdated, MultisigProposalCreated,
+    MultisigProposalApproved, MultisigProposalExecuted, RoleAssigned,
+};
+
+// ==================== Multisig Instructions ====================
+
+#[derive(Accounts)]
+#[instruction(action: MultisigAction)]
+pub struct CreateMultisigProposal<'info> {
+    #[account(mut)]
+    pub member: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"game_state"],
+        bump = game_state.bump,
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        init,
+        payer = member,
+        space = MultisigProposal::SIZE,
+        seeds = [
+            b"multisig_proposal",
+            game_state.key().as_ref(),
+            game_state.multisig.proposal_count.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveMultisigProposal<'info> {
+    pub member: Signer<'info>,
+    
+    #[account(
+        seeds = [b"game_state"],
+        bump = game_state.bump,
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"multisig_proposal",
+            game_state.key().as_ref(),
+            proposal.id.to_le_bytes().as_ref(),
+        ],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+}
+
+/// Accounts for executing a proposal; the proposal's rent goes back to its proposer
+#[derive(Accounts)]
+pub struct ExecuteMultisigProposal<'info> {
+    pub executor: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"game_state"],
+        bump = game_state.bump,
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"multisig_proposal",
+            game_state.key().as_ref(),
+            proposal.id.to_le_bytes().as_ref(),
+        ],
+        bump = proposal.bump,
+        has_one = proposer @ SnitchError::Unauthorized,
+        close = proposer,
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+    
+    /// CHECK: Proposer, checked against the proposal
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    
+    /// Profile for ResetCooldown
+    #[account(mut)]
+    pub player_profile: Option<Account<'info, PlayerProfile>>,
+}
+
+/// Accounts for withdrawing an unexecuted proposal (e.g. one left stale by a signer set change)
+#[derive(Accounts)]
+pub struct CancelMultisigProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"game_state"],
+        bump = game_state.bump,
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        seeds = [
+            b"multisig_proposal",
+            game_state.key().as_ref(),
+            proposal.id.to_le_bytes().as_ref(),
+        ],
+        bump = proposal.bump,
+        has_one = proposer @ SnitchError::Unauthorized,
+        close = proposer,
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+}
+
+pub fn set_multisig_handler(
+    ctx: Context<AdminOnly>,
+    members: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    let multisig = &mut ctx.accounts.game_state.multisig;
+    
+    // Once configured, the signer set only changes through its own proposals
+    // (AdminOnly already requires the multisig to be off)
+    multisig.set_signers(members, threshold)?;
+    
+    emit!(MultisigUpdated {
+        members: multisig.members.clone(),
+        threshold,
+        signer_set_seq: multisig.signer_set_seq,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Multisig set: {}-of-{}", threshold, multisig.members.len());
+    
+    Ok(())
+}
+
+pub fn create_multisig_proposal_handler(
+    ctx: Context<CreateMultisigProposal>,
+    action: MultisigAction,
+) -> Result<()> {
+    let game_state = &mut ctx.accounts.game_state;
+    let proposal = &mut ctx.accounts.proposal;
+    let now = Clock::get()?.unix_timestamp;
+    
+    let member_index = game_state.multisig.member_index(&ctx.accounts.member.key())?;
+    
+    // Reject actions that could never execute
+    match &action {
+        MultisigAction::UpdateConfig { config } => config.validate()?,
+        MultisigAction::SetSigners { members, threshold } => {
+            Multisig::validate_signer_set(members, *threshold)?
+        }
+        MultisigAction::SetConfigTimelock { timelock } => {
+            game_state.validate_config_timelock(*timelock)?
+        }
+        MultisigAction::SetGovernance { voting_period, quorum } => {
+            Governance::validate_params(*voting_period, *quorum)?
+        }
+        _ => {}
+    }
+    
+    proposal.id = game_state.multisig.proposal_count;
+    proposal.proposer = ctx.accounts.member.key();
+    proposal.action = action.clone();
+    proposal.approvals = 0;
+    proposal.signer_set_seq = game_state.multisig.signer_set_seq;
+    proposal.created_at = now;
+    proposal.executed = false;
+    proposal.bump = ctx.bumps.proposal;
+    
+    // The proposer approves implicitly
+    proposal.approve(member_index);
+    
+    game_state.multisig.proposal_count = game_state.multisig.proposal_count
+        .checked_add(1)
+        .ok_or(SnitchError::Overflow)?;
+    
+    emit!(MultisigProposalCreated {
+        proposal_id: proposal.id,
+        proposer: proposal.proposer,
+        action,
+        timestamp: now,
+    });
+    
+    msg!("Multisig proposal {} created", proposal.id);
+    
+    Ok(())
+}
+
+pub fn approve_multisig_proposal_handler(ctx: Context<ApproveMultisigProposal>) -> Result<()> {
+    let multisig = &ctx.accounts.game_state.multisig;
+    let proposal = &mut ctx.accounts.proposal;
+    
+    require!(!proposal.executed, SnitchError::ProposalAlreadyExecuted);
+    require!(
+        proposal.signer_set_seq == multisig.signer_set_seq,
+        SnitchError::StaleProposal
+    );
+    
+    let member_index = multisig.member_index(&ctx.accounts.member.key())?;
+    require!(proposal.approve(member_index), SnitchError::AlreadyApproved);
+    
+    emit!(MultisigProposalApproved {
+        proposal_id: proposal.id,
+        member: ctx.accounts.member.key(),
+        approvals: proposal.approval_count() as u8,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Multisig proposal {} approved ({}/{})",
+        proposal.id,
+        proposal.approval_count(),
+        multisig.threshold
+    );
+    
+    Ok(())
+}
+
+/// Permissionless once the threshold is met
+pub fn execute_multisig_proposal_handler(ctx: Context<ExecuteMultisigProposal>) -> Result<()> {
+    let game_state = &mut ctx.accounts.game_state;
+    let proposal = &mut ctx.accounts.proposal;
+    let now = Clock::get()?.unix_timestamp;
+    
+    require!(!proposal.executed, SnitchError::ProposalAlreadyExecuted);
+    require!(
+        proposal.signer_set_seq == game_state.multisig.signer_set_seq,
+        SnitchError::StaleProposal
+    );
+    require!(
+        game_state.multisig.threshold > 0
+            && proposal.approval_count() >= game_state.multisig.threshold as u32,
+        SnitchError::ThresholdNotMet
+    );
+    
+    proposal.executed = true;
+    
+    match proposal.action.clone() {
+        MultisigAction::UpdateConfig { config } => {
+            let activates_at = game_state.propose_config(config.clone(), now)?;
+            emit!(ConfigProposed {
+                proposed_by: game_state.key(),
+                config,
+                activates_at,
+                timestamp: now,
+            });
+        }
+        MultisigAction::CancelConfig => {
+            require!(game_state.pending_config.is_some(), SnitchError::NoPendingConfig);
+            game_state.pending_config = None;
+            game_state.pending_config_activates_at = 0;
+            emit!(ConfigCancelled {
+                cancelled_by: game_state.key(),
+                timestamp: now,
+            });
+        }
+        MultisigAction::Pause => {
+            require!(!game_state.paused, SnitchError::InvalidConfig);
+            game_state.paused = true;
+        }
+        MultisigAction::Resume => {
+            require!(game_state.paused, SnitchError::InvalidConfig);
+            game_state.paused = false;
+        }
+        MultisigAction::ResetCooldown { player } => {
+            let player_profile = ctx.accounts.player_profile
+                .as_mut()
+                .ok_or(SnitchError::MissingAccount)?;
+            require!(player_profile.player == player, SnitchError::Unauthorized);
+            player_profile.clear_cooldown();
+        }
+        MultisigAction::SetSigners { members, threshold } => {
+            game_state.multisig.set_signers(members, threshold)?;
+            emit!(MultisigUpdated {
+                members: game_state.multisig.members.clone(),
+                threshold,
+                signer_set_seq: game_state.multisig.signer_set_seq,
+                timestamp: now,
+            });
+        }
+        MultisigAction::SetConfigTimelock { timelock } => {
+            game_state.set_config_timelock(timelock)?;
+        }
+        MultisigAction::ProposeAuthority { new_authority } => {
+            game_state.propose_authority(new_authority)?;
+            emit!(AuthorityProposed {
+                authority: game_state.authority,
+                pending_authority: new_authority,
+                timestamp: now,
+            });
+        }
+        MultisigAction::AssignRole { role, holder } => {
+            let previous_holder = game_state.roles.holder(role);
+            game_state.roles.set(role, holder);
+            emit!(RoleAssigned {
+                role,
+                previous_holder,
+                holder,
+                timestamp: now,
+            });
+        }
+        MultisigAction::SetGovernance { voting_period, quorum } => {
+            game_state.governance.set(voting_period, quorum)?;
+        }
+    }
+    
+    emit!(MultisigProposalExecuted {
+        proposal_id: proposal.id,
+        executed_by: ctx.accounts.executor.key(),
+        timestamp: now,
+    });
+    
+    msg!("Multisig proposal {} executed", proposal.id);
+    
+    Ok(())
+}
+
+pub fn cancel_multisig_proposal_handler(ctx: Context<CancelMultisigProposal>) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    
+    require!(!proposal.executed, SnitchError::ProposalAlreadyExecuted);
+    
+    msg!("Multisig proposal {} withdrawn by {}", proposal.id, proposal.proposer);
+    
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer, Token, TokenAccount};
+use solana_program::clock::Clock;
+
+use crate::state::*;
+use crate::SnitchError;
+use crate::BribeOffered;
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct OfferBribe<'info> {
+    #[account(mut)]
+    pub briber: Signer<'info>,
+    
+    #[account(
+        seeds = [b"game_state"],
+        bump = game_state.bump,
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        constraint = interrogation.key() == game_state.active_interrogation.unwrap_or_default() @ SnitchError::NoActiveRound,
+    )]
+    pub interrogation: Account<'info, Interrogation>,
+    
+    /// Briber's token account
+    #[account(
+        mut,
+        constraint = briber_token.owner == briber.key() @ SnitchError::Unauthorized,
+        constraint = briber_token.mint == game_state.token_mint @ SnitchError::Unauthorized,
+    )]
+    pub briber_token: Account<'info, TokenAccount>,
+    
+    /// Escrow holding this round's bribes
+    #[account(
+        mut,
+        seeds = [b"bribe_escrow", interrogation.key().as_ref()],
+        bump,
+    )]
+    pub bribe_escrow: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<OfferBribe>,
+    amount: u64,
+) -> Result<()> {
+    let game_state = &ctx.accounts.game_state;
+    let interrogation = &mut ctx.accounts.interrogation;
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    
+    // Validate game state
+    game_state.validate_not_paused()?;
+    
+    require!(amount > 0, SnitchError::InvalidAmount);
+    
+    // Bribes can only be offered while choices are still open
+    require!(
+        interrogation.can_commit(now),
+        SnitchError::CommitPhaseEnded
+    );
+    
+    // Validate briber is in this round
+    let briber_key = ctx.accounts.briber.key();
+    let is_player_a = interrogation.player_a == briber_key;
+    require!(
+        interrogation.is_player_in_round(&briber_key),
+        SnitchError::PlayerNotInInterrogation
+    );
+    
+    // Escrow the bribe
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.briber_token.to_account_info(),
+            to: ctx.accounts.bribe_escrow.to_account_info(),
+            authority: ctx.accounts.briber.to_account_info(),
+        },
+    );
+    token::transfer(transfer_ctx, amount)?;
+    
+    let (recipient, total) = if is_player_a {
+        interrogation.bribe_a = interrogation.bribe_a
+            .checked_add(amount)
+            .ok_or(SnitchError::Overflow)?;
+        (interrogation.player_b, interrogation.bribe_a)
+    } else {
+        interrogation.bribe_b = interrogation.bribe_b
+            .checked_add(amount)
+            .ok_or(SnitchError::Overflow)?;
+        (interrogation.player_a, interrogation.bribe_b)
+    };
+    
+    // Emit event
+    emit!(BribeOffered {
+        round_id: interrogation.round_id,
+        briber: briber_key,
+        recipient,
+        amount,
+        total,
+        timestamp: now,
+    });
+    
+    msg!("Player {} offered {} to {} in round {}", 
+        briber_key, amount, recipient, interrogation.round_id);
+    
+    Ok(())
+}
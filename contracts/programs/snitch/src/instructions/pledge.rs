@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use solana_program::clock::Clock;
+
+use crate::state::*;
+use crate::SnitchError;
+use crate::PledgeMade;
+
+#[derive(Accounts)]
+#[instruction(choice: u8)]
+pub struct Pledge<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    #[account(
+        seeds = [b"game_state"],
+        bump = game_state.bump,
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        constraint = interrogation.round_id == player_profile.current_round @ SnitchError::PlayerNotInInterrogation,
+    )]
+    pub interrogation: Account<'info, Interrogation>,
+    
+    #[account(
+        seeds = [b"player", player.key().as_ref()],
+        bump = player_profile.bump,
+        constraint = player_profile.player == player.key() @ SnitchError::Unauthorized,
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+}
+
+pub fn handler(
+    ctx: Context<Pledge>,
+    choice: u8,
+) -> Result<()> {
+    let game_state = &ctx.accounts.game_state;
+    let interrogation = &mut ctx.accounts.interrogation;
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    
+    // Validate game state
+    game_state.validate_not_paused()?;
+    
+    // Validate choice
+    let pledge = Choice::from_u8(choice)?;
+    
+    // Pledges are only allowed during the commit phase
+    require!(
+        interrogation.can_commit(now),
+        SnitchError::CommitPhaseEnded
+    );
+    
+    // Validate player is in this round
+    let player_key = ctx.accounts.player.key();
+    require!(
+        interrogation.is_player_in_round(&player_key),
+        SnitchError::PlayerNotInInterrogation
+    );
+    
+    // A pledge must be made before committing and cannot be withdrawn
+    let is_player_a = interrogation.player_a == player_key;
+    
+    if is_player_a {
+        require!(
+            interrogation.commitment_a.is_none(),
+            SnitchError::AlreadyCommitted
+        );
+        require!(
+            interrogation.pledge_a.is_none(),
+            SnitchError::AlreadyPledged
+        );
+        interrogation.pledge_a = Some(pledge);
+    } else {
+        require!(
+            interrogation.commitment_b.is_none(),
+            SnitchError::AlreadyCommitted
+        );
+        require!(
+            interrogation.pledge_b.is_none(),
+            SnitchError::AlreadyPledged
+        );
+        interrogation.pledge_b = Some(pledge);
+    }
+    
+    // Emit event
+    emit!(PledgeMade {
+        round_id: interrogation.round_id,
+        player: player_key,
+        pledge,
+        timestamp: now,
+    });
+    
+    msg!("Player {} pledged {:?} in round {}", 
+        player_key, pledge, interrogation.round_id);
+    
+    Ok(())
+}
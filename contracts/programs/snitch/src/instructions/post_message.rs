@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use solana_program::clock::Clock;
+
+use crate::state::*;
+use crate::SnitchError;
+use crate::MessagePosted;
+
+#[derive(Accounts)]
+#[instruction(text: String)]
+pub struct PostMessage<'info> {
+    #[account(mut)]
+    pub sender: Signer<'info>,
+    
+    #[account(
+        seeds = [b"game_state"],
+        bump = game_state.bump,
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        constraint = interrogation.key() == game_state.active_interrogation.unwrap_or_default() @ SnitchError::NoActiveRound,
+    )]
+    pub interrogation: Account<'info, Interrogation>,
+    
+    /// Interrogation-room chat for this round (init on first message)
+    #[account(
+        init_if_needed,
+        payer = sender,
+        space = MessageLog::SIZE,
+        seeds = [b"message_log", interrogation.key().as_ref()],
+        bump,
+    )]
+    pub message_log: Account<'info, MessageLog>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<PostMessage>,
+    text: String,
+) -> Result<()> {
+    let game_state = &ctx.accounts.game_state;
+    let interrogation = &ctx.accounts.interrogation;
+    let message_log = &mut ctx.accounts.message_log;
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    
+    // Validate game state
+    game_state.validate_not_paused()?;
+    
+    // Suspects may only talk while choices are still open
+    require!(
+        interrogation.can_commit(now),
+        SnitchError::CommitPhaseEnded
+    );
+    
+    // Validate sender is in this round
+    let sender_key = ctx.accounts.sender.key();
+    require!(
+        interrogation.is_player_in_round(&sender_key),
+        SnitchError::PlayerNotInInterrogation
+    );
+    
+    require!(
+        text.len() <= MessageLog::MAX_MESSAGE_LEN,
+        SnitchError::MessageTooLong
+    );
+    require!(
+        message_log.messages.len() < MessageLog::MAX_MESSAGES,
+        SnitchError::MessageLogFull
+    );
+    
+    // First message initializes the log
+    if message_log.interrogation == Pubkey::default() {
+        message_log.interrogation = interrogation.key();
+        message_log.round_id = interrogation.round_id;
+        message_log.payer = sender_key;
+        message_log.bump = ctx.bumps.message_log;
+    }
+    
+    let index = message_log.messages.len() as u8;
+    message_log.messages.push(Message {
+        sender: sender_key,
+        posted_at: now,
+        text: text.clone(),
+    });
+    
+    // Emit event
+    emit!(MessagePosted {
+        round_id: interrogation.round_id,
+        sender: sender_key,
+        index,
+        text,
+        timestamp: now,
+    });
+    
+    msg!("Player {} posted message {} in round {}", 
+        sender_key, index, interrogation.round_id);
+    
+    Ok(())
+}
@@ -1,13 +1,17 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Transfer, Token, TokenAccount, Burn};
+use anchor_spl::token::{self, Transfer, Token, TokenAccount, Mint, Burn};
 use solana_program::clock::Clock;
 
 use crate::state::*;
+use crate::utils::{
+    calculate_yield_reward, calculate_lawyer_fee, autopilot_entropy, signed_amount, signed_loss,
+};
 use crate::SnitchError;
 use crate::RoundResolved;
 
 #[derive(Accounts)]
 pub struct ResolveRound<'info> {
+    /// Anyone may resolve; for `forfeit` this is the forfeiting player
     #[account(mut)]
     pub resolver: Signer<'info>,
     
@@ -20,7 +24,7 @@ pub struct ResolveRound<'info> {
     
     #[account(
         mut,
-        constraint = interrogation.key() == game_state.active_interrogation.unwrap_or(Pubkey::default()) @ SnitchError::NoActiveRound,
+        constraint = interrogation.key() == game_state.active_interrogation.unwrap_or_default() @ SnitchError::NoActiveRound,
     )]
     pub interrogation: Account<'info, Interrogation>,
     
@@ -40,19 +44,47 @@ pub struct ResolveRound<'info> {
     )]
     pub player_b_profile: Account<'info, PlayerProfile>,
     
-    /// Player A's token account
+    /// Head-to-head history for this pair (created at selection)
     #[account(
         mut,
-        constraint = player_a_token.owner == interrogation.player_a @ SnitchError::Unauthorized,
-        constraint = player_a_token.mint == game_state.token_mint @ SnitchError::Unauthorized,
+        seeds = [
+            b"pair_history",
+            interrogation.player_lo().as_ref(),
+            interrogation.player_hi().as_ref(),
+        ],
+        bump = pair_history.bump,
+    )]
+    pub pair_history: Account<'info, PairHistory>,
+    
+    /// Top-N rankings (required once initialized)
+    #[account(
+        mut,
+        seeds = [b"leaderboard", game_state.key().as_ref()],
+        bump,
+    )]
+    pub leaderboard: Option<AccountLoader<'info, Leaderboard>>,
+    
+    /// Recent round history (required once initialized)
+    #[account(
+        mut,
+        seeds = [b"round_history", game_state.key().as_ref()],
+        bump,
+    )]
+    pub round_history: Option<AccountLoader<'info, RoundHistory>>,
+    
+    /// Player A's associated token account (its balance sizes the timeout fee)
+    #[account(
+        mut,
+        associated_token::mint = game_state.token_mint,
+        associated_token::authority = interrogation.player_a,
     )]
     pub player_a_token: Account<'info, TokenAccount>,
     
-    /// Player B's token account
+    /// Player B's associated token account (its balance sizes the timeout fee)
     #[account(
         mut,
-        constraint = player_b_token.owner == interrogation.player_b @ SnitchError::Unauthorized,
-        constraint = player_b_token.mint == game_state.token_mint @ SnitchError::Unauthorized,
+        associated_token::mint = game_state.token_mint,
+        associated_token::authority = interrogation.player_b,
     )]
     pub player_b_token: Account<'info, TokenAccount>,
     
@@ -64,6 +96,29 @@ pub struct ResolveRound<'info> {
     )]
     pub yield_vault: Account<'info, TokenAccount>,
     
+    /// Side pot holding this round's double-down wagers
+    #[account(
+        mut,
+        seeds = [b"side_pot", interrogation.key().as_ref()],
+        bump,
+    )]
+    pub side_pot: Account<'info, TokenAccount>,
+    
+    /// Escrow holding this round's bribe offers
+    #[account(
+        mut,
+        seeds = [b"bribe_escrow", interrogation.key().as_ref()],
+        bump,
+    )]
+    pub bribe_escrow: Account<'info, TokenAccount>,
+    
+    /// The SNITCH token mint (for burning the side pot)
+    #[account(
+        mut,
+        address = game_state.token_mint @ SnitchError::Unauthorized,
+    )]
+    pub token_mint: Account<'info, Mint>,
+    
     /// CHECK: Token authority (PDA)
     #[account(
         seeds = [b"game_state"],
@@ -72,6 +127,7 @@ pub struct ResolveRound<'info> {
     pub token_authority: AccountInfo<'info>,
     
     pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<ResolveRound>) -> Result<()> {
@@ -93,12 +149,56 @@ pub fn handler(ctx: Context<ResolveRound>) -> Result<()> {
         SnitchError::AlreadyResolved
     );
     
+    // Rankings and history can't be skipped once the game has them
+    check_registered(
+        &mut game_state.leaderboard,
+        ctx.accounts.leaderboard.as_ref().map(|leaderboard| leaderboard.key()),
+    )?;
+    check_registered(
+        &mut game_state.round_history,
+        ctx.accounts.round_history.as_ref().map(|round_history| round_history.key()),
+    )?;
+    
     // Get choices (None if not revealed)
-    let choice_a = interrogation.choice_a;
-    let choice_b = interrogation.choice_b;
+    let mut choice_a = interrogation.choice_a;
+    let mut choice_b = interrogation.choice_b;
+    
+    // Absent players are played by their autopilot strategy, if any
+    let forfeited = interrogation.forfeited_by.is_some();
+    let mut autopilot_a = None;
+    let mut autopilot_b = None;
+    
+    if !forfeited && interrogation.commitment_a.is_none() {
+        let strategy = ctx.accounts.player_a_profile.strategy;
+        let entropy = autopilot_entropy(&interrogation.key(), &interrogation.player_a, &interrogation.selection_seed);
+        let opponent_last_choice = ctx.accounts.pair_history
+            .last_choice_of(&interrogation.player_b)
+            .or(ctx.accounts.player_b_profile.last_choice);
+        if let Some(choice) = strategy.decide(opponent_last_choice, entropy) {
+            choice_a = Some(choice);
+            autopilot_a = Some(strategy);
+        }
+    }
+    if !forfeited && interrogation.commitment_b.is_none() {
+        let strategy = ctx.accounts.player_b_profile.strategy;
+        let entropy = autopilot_entropy(&interrogation.key(), &interrogation.player_b, &interrogation.selection_seed);
+        let opponent_last_choice = ctx.accounts.pair_history
+            .last_choice_of(&interrogation.player_a)
+            .or(ctx.accounts.player_a_profile.last_choice);
+        if let Some(choice) = strategy.decide(opponent_last_choice, entropy) {
+            choice_b = Some(choice);
+            autopilot_b = Some(strategy);
+        }
+    }
+    
+    interrogation.choice_a = choice_a;
+    interrogation.choice_b = choice_b;
+    interrogation.autopilot_a = autopilot_a.is_some();
+    interrogation.autopilot_b = autopilot_b.is_some();
     
     // Determine outcome and calculate rewards
-    let config = &game_state.config;
+    // Judge the round by the rules in force when the players were selected
+    let config = &interrogation.payoff_config.clone();
     let balance_a = ctx.accounts.player_a_token.amount;
     let balance_b = ctx.accounts.player_b_token.amount;
     
@@ -116,17 +216,80 @@ pub fn handler(ctx: Context<ResolveRound>) -> Result<()> {
         choice_b,
         balance_a,
         balance_b,
+        config.streak_yield_bps(ctx.accounts.player_a_profile.cooperation_streak),
+        config.streak_yield_bps(ctx.accounts.player_b_profile.cooperation_streak),
         game_state.yield_pool,
         config,
     )?;
     
+    // A forfeit may carry a lighter penalty than a real betrayal
+    let (reward_a, reward_b, penalty_a, penalty_b) = if forfeited {
+        (
+            scale_forfeit_amount(reward_a, config)?,
+            scale_forfeit_amount(reward_b, config)?,
+            scale_forfeit_amount(penalty_a, config)?,
+            scale_forfeit_amount(penalty_b, config)?,
+        )
+    } else {
+        (reward_a, reward_b, penalty_a, penalty_b)
+    };
+    
+    // Committing and then going silent must not dodge the lawyer fee: a player
+    // who committed but never revealed pays it as if they had lawyered up
+    let timeout_fee_a = if !forfeited && interrogation.commitment_a.is_some() && !interrogation.revealed_a {
+        calculate_lawyer_fee(balance_a, config)?
+    } else {
+        0
+    };
+    let timeout_fee_b = if !forfeited && interrogation.commitment_b.is_some() && !interrogation.revealed_b {
+        calculate_lawyer_fee(balance_b, config)?
+    } else {
+        0
+    };
+    let (reward_a, timeout_fee_a, fee_from_pending_a) =
+        withhold_timeout_fee(&mut ctx.accounts.player_a_profile, reward_a, timeout_fee_a);
+    let (reward_b, timeout_fee_b, fee_from_pending_b) =
+        withhold_timeout_fee(&mut ctx.accounts.player_b_profile, reward_b, timeout_fee_b);
+    
+    // Fees taken from unclaimed rewards go back to the pool that reserved them
+    game_state.yield_pool = game_state.yield_pool
+        .checked_add(fee_from_pending_a)
+        .ok_or(SnitchError::Overflow)?
+        .checked_add(fee_from_pending_b)
+        .ok_or(SnitchError::Overflow)?;
+    interrogation.lawyer_fee_a = interrogation.lawyer_fee_a.saturating_add(timeout_fee_a);
+    interrogation.lawyer_fee_b = interrogation.lawyer_fee_b.saturating_add(timeout_fee_b);
+    interrogation.pnl_a.fees = interrogation.pnl_a.fees.saturating_add(timeout_fee_a);
+    interrogation.pnl_b.fees = interrogation.pnl_b.fees.saturating_add(timeout_fee_b);
+    
+    // Settle the side pot by the same outcome
+    let (
+        side_pot_to_a,
+        side_pot_to_b,
+        side_bonus_a,
+        side_bonus_b,
+        side_pot_burned,
+    ) = calculate_side_pot(
+        outcome,
+        interrogation.side_pot_a,
+        interrogation.side_pot_b,
+        game_state.yield_pool
+            .saturating_sub(reward_a)
+            .saturating_sub(reward_b),
+        forfeited,
+        config,
+    )?;
+    
     // Update interrogation
     interrogation.outcome = outcome;
     interrogation.player_a_reward = reward_a;
     interrogation.player_b_reward = reward_b;
-    interrogation.amount_burned = burn_a.saturating_add(burn_b);
+    interrogation.amount_burned = burn_a
+        .saturating_add(burn_b)
+        .saturating_add(side_pot_burned);
     interrogation.phase = Phase::Resolved;
     interrogation.rewards_distributed = true;
+    interrogation.resolved_at = now;
     
     // Update game state
     game_state.active_interrogation = None;
@@ -137,6 +300,16 @@ pub fn handler(ctx: Context<ResolveRound>) -> Result<()> {
         .checked_add(interrogation.amount_burned)
         .ok_or(SnitchError::Overflow)?;
     
+    // Record the head-to-head result (a forfeit is not a real decision)
+    if !forfeited {
+        ctx.accounts.pair_history.record(
+            interrogation,
+            outcome,
+            choice_a.unwrap_or(Choice::Cooperate),
+            choice_b.unwrap_or(Choice::Cooperate),
+        )?;
+    }
+    
     // Update player profiles
     let player_a_profile = &mut ctx.accounts.player_a_profile;
     let player_b_profile = &mut ctx.accounts.player_b_profile;
@@ -145,6 +318,53 @@ pub fn handler(ctx: Context<ResolveRound>) -> Result<()> {
     player_a_profile.current_round = 0;
     player_b_profile.current_round = 0;
     
+    // Autopilot choices count towards stats like revealed ones
+    if autopilot_a.is_some() {
+        player_a_profile.record_choice(choice_a.unwrap_or(Choice::Cooperate))?;
+    }
+    if autopilot_b.is_some() {
+        player_b_profile.record_choice(choice_b.unwrap_or(Choice::Cooperate))?;
+    }
+    
+    // Forfeits leave choice history, streaks, badges and trust untouched
+    if !forfeited {
+        player_a_profile.last_choice = Some(choice_a.unwrap_or(Choice::Cooperate));
+        player_b_profile.last_choice = Some(choice_b.unwrap_or(Choice::Cooperate));
+        
+        // Streaks (after the yield used this round's streak bonus)
+        player_a_profile.record_streaks(choice_a.unwrap_or(Choice::Cooperate), penalty_a);
+        player_b_profile.record_streaks(choice_b.unwrap_or(Choice::Cooperate), penalty_b);
+        
+        // Award milestone badges (minted later via mint_badge)
+        match outcome {
+            Outcome::PlayerASnitched if choice_b.unwrap_or(Choice::Cooperate) == Choice::Cooperate => {
+                player_a_profile.award_badge(Badge::FirstBetrayal);
+                player_b_profile.award_badge(Badge::SurvivedSnitch);
+            }
+            Outcome::PlayerBSnitched if choice_a.unwrap_or(Choice::Cooperate) == Choice::Cooperate => {
+                player_b_profile.award_badge(Badge::FirstBetrayal);
+                player_a_profile.award_badge(Badge::SurvivedSnitch);
+            }
+            Outcome::MutualSnitching => {
+                player_a_profile.award_badge(Badge::MutualDestruction);
+                player_b_profile.award_badge(Badge::MutualDestruction);
+            }
+            _ => {}
+        }
+        if player_a_profile.times_cooperated >= 5 {
+            player_a_profile.award_badge(Badge::FiveTimeCooperator);
+        }
+        if player_b_profile.times_cooperated >= 5 {
+            player_b_profile.award_badge(Badge::FiveTimeCooperator);
+        }
+        
+        // Trust scores
+        let effective_a = choice_a.unwrap_or(Choice::Cooperate);
+        let effective_b = choice_b.unwrap_or(Choice::Cooperate);
+        player_a_profile.update_trust_score(effective_a, effective_b, now);
+        player_b_profile.update_trust_score(effective_b, effective_a, now);
+    }
+    
     // Apply penalties to profiles
     if penalty_a > 0 {
         player_a_profile.add_penalty(penalty_a)?;
@@ -152,8 +372,39 @@ pub fn handler(ctx: Context<ResolveRound>) -> Result<()> {
     if penalty_b > 0 {
         player_b_profile.add_penalty(penalty_b)?;
     }
+    // The share of a timeout fee withheld from this round's reward is already
+    // netted out of the booked reward; only the share from pending rewards is a loss
+    if timeout_fee_a > 0 {
+        player_a_profile.add_penalty(timeout_fee_a)?;
+    }
+    if fee_from_pending_a > 0 {
+        player_a_profile.book_pnl(signed_loss(fee_from_pending_a)?)?;
+    }
+    if timeout_fee_b > 0 {
+        player_b_profile.add_penalty(timeout_fee_b)?;
+    }
+    if fee_from_pending_b > 0 {
+        player_b_profile.book_pnl(signed_loss(fee_from_pending_b)?)?;
+    }
+    
+    // Track broken pledges against the choice actually played (not on forfeit)
+    let promise_broken_a = !forfeited && interrogation.pledge_a
+        .is_some_and(|pledge| pledge != choice_a.unwrap_or(Choice::Cooperate));
+    let promise_broken_b = !forfeited && interrogation.pledge_b
+        .is_some_and(|pledge| pledge != choice_b.unwrap_or(Choice::Cooperate));
+    
+    if promise_broken_a {
+        player_a_profile.promises_broken = player_a_profile.promises_broken
+            .checked_add(1)
+            .ok_or(SnitchError::Overflow)?;
+    }
+    if promise_broken_b {
+        player_b_profile.promises_broken = player_b_profile.promises_broken
+            .checked_add(1)
+            .ok_or(SnitchError::Overflow)?;
+    }
     
-    // Add rewards to pending (or handle immediate transfer)
+    // Rewards are paid out of the yield vault by claim_rewards
     if reward_a > 0 {
         player_a_profile.add_pending_rewards(reward_a)?;
     }
@@ -161,6 +412,46 @@ pub fn handler(ctx: Context<ResolveRound>) -> Result<()> {
         player_b_profile.add_pending_rewards(reward_b)?;
     }
     
+    // Bribes are paid only if the recipient revealed COOPERATE, refunded otherwise
+    // A forfeiting player never collects a bribe
+    let bribe_a_paid = choice_b == Some(Choice::Cooperate)
+        && interrogation.forfeited_by != Some(interrogation.player_b);
+    let bribe_b_paid = choice_a == Some(Choice::Cooperate)
+        && interrogation.forfeited_by != Some(interrogation.player_a);
+    
+    // Book this round's token movements into each player's PnL
+    interrogation.pnl_a.rewards = reward_a;
+    interrogation.pnl_a.side_pot = signed_amount(side_pot_to_a.saturating_add(side_bonus_a))?
+        .checked_sub(signed_amount(interrogation.side_pot_a)?)
+        .ok_or(SnitchError::Overflow)?;
+    interrogation.pnl_a.bribes = bribe_pnl(interrogation.bribe_b, bribe_b_paid, interrogation.bribe_a, bribe_a_paid)?;
+    interrogation.pnl_b.rewards = reward_b;
+    interrogation.pnl_b.side_pot = signed_amount(side_pot_to_b.saturating_add(side_bonus_b))?
+        .checked_sub(signed_amount(interrogation.side_pot_b)?)
+        .ok_or(SnitchError::Overflow)?;
+    interrogation.pnl_b.bribes = bribe_pnl(interrogation.bribe_a, bribe_a_paid, interrogation.bribe_b, bribe_b_paid)?;
+    
+    for (profile, pnl) in [
+        (&mut *player_a_profile, interrogation.pnl_a),
+        (&mut *player_b_profile, interrogation.pnl_b),
+    ] {
+        profile.book_pnl(signed_amount(pnl.rewards)?)?;
+        profile.book_pnl(pnl.side_pot)?;
+        profile.book_pnl(pnl.bribes)?;
+    }
+    
+    // Refresh rankings with both players' updated stats (not on forfeit)
+    if let Some(leaderboard) = ctx.accounts.leaderboard.as_ref().filter(|_| !forfeited) {
+        let mut leaderboard = leaderboard.load_mut()?;
+        leaderboard.record(player_a_profile, interrogation.round_id);
+        leaderboard.record(player_b_profile, interrogation.round_id);
+    }
+    
+    // Append the round to the on-chain history
+    if let Some(round_history) = &ctx.accounts.round_history {
+        round_history.load_mut()?.push(RoundRecord::new(interrogation));
+    }
+    
     // Execute token transfers and burns
     let seeds = &[
         b"game_state",
@@ -168,23 +459,39 @@ pub fn handler(ctx: Context<ResolveRound>) -> Result<()> {
     ];
     let signer = &[&seeds[..]];
     
-    // Burn from player A if needed
-    if burn_a > 0 {
-        let burn_ctx = CpiContext::new(
+    // Note: Burning from player requires delegate or direct transfer
+    // In practice, we'd need player-signed burn or escrow
+    // For this implementation, penalties are tracked and claimed from yield
+    
+    // Pay out the side pot
+    if side_pot_to_a > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
-            Burn {
-                mint: ctx.accounts.player_a_token.to_account_info(),
-                from: ctx.accounts.player_a_token.to_account_info(),
+            Transfer {
+                from: ctx.accounts.side_pot.to_account_info(),
+                to: ctx.accounts.player_a_token.to_account_info(),
                 authority: ctx.accounts.token_authority.to_account_info(),
             },
+            signer,
         );
-        // Note: Burning from player requires delegate or direct transfer
-        // In practice, we'd need player-signed burn or escrow
-        // For this implementation, penalties are tracked and claimed from yield
+        token::transfer(transfer_ctx, side_pot_to_a)?;
     }
     
-    // Distribute yield rewards from vault
-    if reward_a > 0 {
+    if side_pot_to_b > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.side_pot.to_account_info(),
+                to: ctx.accounts.player_b_token.to_account_info(),
+                authority: ctx.accounts.token_authority.to_account_info(),
+            },
+            signer,
+        );
+        token::transfer(transfer_ctx, side_pot_to_b)?;
+    }
+    
+    // Cooperation bonus on side wagers comes from the yield vault
+    if side_bonus_a > 0 {
         let transfer_ctx = CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
             Transfer {
@@ -194,10 +501,10 @@ pub fn handler(ctx: Context<ResolveRound>) -> Result<()> {
             },
             signer,
         );
-        token::transfer(transfer_ctx, reward_a)?;
+        token::transfer(transfer_ctx, side_bonus_a)?;
     }
     
-    if reward_b > 0 {
+    if side_bonus_b > 0 {
         let transfer_ctx = CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
             Transfer {
@@ -207,18 +514,70 @@ pub fn handler(ctx: Context<ResolveRound>) -> Result<()> {
             },
             signer,
         );
-        token::transfer(transfer_ctx, reward_b)?;
+        token::transfer(transfer_ctx, side_bonus_b)?;
+    }
+    
+    // Mutual snitching burns the side pot
+    if side_pot_burned > 0 {
+        let burn_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.token_mint.to_account_info(),
+                from: ctx.accounts.side_pot.to_account_info(),
+                authority: ctx.accounts.token_authority.to_account_info(),
+            },
+            signer,
+        );
+        token::burn(burn_ctx, side_pot_burned)?;
+    }
+    
+    if interrogation.bribe_a > 0 {
+        let to = if bribe_a_paid {
+            ctx.accounts.player_b_token.to_account_info()
+        } else {
+            ctx.accounts.player_a_token.to_account_info()
+        };
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.bribe_escrow.to_account_info(),
+                to,
+                authority: ctx.accounts.token_authority.to_account_info(),
+            },
+            signer,
+        );
+        token::transfer(transfer_ctx, interrogation.bribe_a)?;
     }
     
-    // Update yield pool
+    if interrogation.bribe_b > 0 {
+        let to = if bribe_b_paid {
+            ctx.accounts.player_a_token.to_account_info()
+        } else {
+            ctx.accounts.player_b_token.to_account_info()
+        };
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.bribe_escrow.to_account_info(),
+                to,
+                authority: ctx.accounts.token_authority.to_account_info(),
+            },
+            signer,
+        );
+        token::transfer(transfer_ctx, interrogation.bribe_b)?;
+    }
+    
+    // Update yield pool (credited rewards are reserved until claimed)
     game_state.yield_pool = game_state.yield_pool
         .saturating_sub(reward_a)
-        .saturating_sub(reward_b);
+        .saturating_sub(reward_b)
+        .saturating_sub(side_bonus_a)
+        .saturating_sub(side_bonus_b);
     
     game_state.total_rewards_distributed = game_state.total_rewards_distributed
-        .checked_add(reward_a)
+        .checked_add(side_bonus_a)
         .ok_or(SnitchError::Overflow)?
-        .checked_add(reward_b)
+        .checked_add(side_bonus_b)
         .ok_or(SnitchError::Overflow)?;
     
     // Emit event
@@ -232,6 +591,24 @@ pub fn handler(ctx: Context<ResolveRound>) -> Result<()> {
         player_a_reward: reward_a,
         player_b_reward: reward_b,
         amount_burned: interrogation.amount_burned,
+        lawyer_fee_a: interrogation.lawyer_fee_a,
+        lawyer_fee_b: interrogation.lawyer_fee_b,
+        side_pot_a: interrogation.side_pot_a,
+        side_pot_b: interrogation.side_pot_b,
+        side_pot_payout_a: side_pot_to_a.saturating_add(side_bonus_a),
+        side_pot_payout_b: side_pot_to_b.saturating_add(side_bonus_b),
+        side_pot_burned,
+        bribe_a: interrogation.bribe_a,
+        bribe_b: interrogation.bribe_b,
+        bribe_a_paid: interrogation.bribe_a > 0 && bribe_a_paid,
+        bribe_b_paid: interrogation.bribe_b > 0 && bribe_b_paid,
+        promise_broken_a,
+        promise_broken_b,
+        autopilot_a,
+        autopilot_b,
+        forfeited_by: interrogation.forfeited_by,
+        trust_score_a: ctx.accounts.player_a_profile.current_trust_score(now),
+        trust_score_b: ctx.accounts.player_b_profile.current_trust_score(now),
         timestamp: now,
     });
     
@@ -243,13 +620,16 @@ pub fn handler(ctx: Context<ResolveRound>) -> Result<()> {
 }
 
 /// Calculate the outcome and rewards
+#[allow(clippy::too_many_arguments)]
 fn calculate_outcome(
     choice_a: Option<Choice>,
     choice_b: Option<Choice>,
     balance_a: u64,
     balance_b: u64,
+    yield_bps_a: u64,
+    yield_bps_b: u64,
     yield_pool: u64,
-    config: &GameConfig,
+    config: &PayoffConfig,
 ) -> Result<(Outcome, u64, u64, u64, u64, u64, u64)> {
     // Default to timeout if no reveals
     let choice_a = choice_a.unwrap_or(Choice::Cooperate);
@@ -258,23 +638,23 @@ fn calculate_outcome(
     let bps = config.bps_denominator as u64;
     
     match (choice_a, choice_b) {
-        // Both cooperate: both get 5% yield
+        // Both cooperate: both get 5% yield (plus any streak bonus)
         (Choice::Cooperate, Choice::Cooperate) => {
             let yield_a = (balance_a as u128)
-                .checked_mul(config.cooperation_yield_bps as u128)
+                .checked_mul(yield_bps_a as u128)
                 .ok_or(SnitchError::Overflow)?
                 .checked_div(bps as u128)
                 .ok_or(SnitchError::Underflow)? as u64;
             
             let yield_b = (balance_b as u128)
-                .checked_mul(config.cooperation_yield_bps as u128)
+                .checked_mul(yield_bps_b as u128)
                 .ok_or(SnitchError::Overflow)?
                 .checked_div(bps as u128)
                 .ok_or(SnitchError::Underflow)? as u64;
             
             // Cap at available yield pool
             let actual_yield_a = yield_a.min(yield_pool / 2);
-            let actual_yield_b = yield_b.min((yield_pool - actual_yield_a).max(0));
+            let actual_yield_b = yield_b.min(yield_pool - actual_yield_a);
             
             Ok((
                 Outcome::MutualCooperation,
@@ -353,5 +733,185 @@ fn calculate_outcome(
                 penalty_b,
             ))
         }
+        
+        // A snitches, B lawyered up: A gets the unshielded part of B's loss
+        (Choice::Snitch, Choice::LawyerUp) => {
+            let reward_a = calculate_lawyered_snitch_reward(balance_b, config)?;
+            
+            Ok((
+                Outcome::PlayerASnitched,
+                reward_a,
+                0,
+                0,   // burn_a
+                0,   // burn_b
+                0,   // penalty_a
+                reward_a, // penalty_b
+            ))
+        }
+        
+        // B snitches, A lawyered up: B gets the unshielded part of A's loss
+        (Choice::LawyerUp, Choice::Snitch) => {
+            let reward_b = calculate_lawyered_snitch_reward(balance_a, config)?;
+            
+            Ok((
+                Outcome::PlayerBSnitched,
+                0,
+                reward_b,
+                0,   // burn_a
+                0,   // burn_b
+                reward_b, // penalty_a
+                0,   // penalty_b
+            ))
+        }
+        
+        // A lawyered up against a cooperator: fee already paid, no yield
+        (Choice::LawyerUp, Choice::Cooperate) => {
+            Ok((Outcome::PlayerALawyeredUp, 0, 0, 0, 0, 0, 0))
+        }
+        
+        // B lawyered up against a cooperator: fee already paid, no yield
+        (Choice::Cooperate, Choice::LawyerUp) => {
+            Ok((Outcome::PlayerBLawyeredUp, 0, 0, 0, 0, 0, 0))
+        }
+        
+        // Both lawyered up: nobody talks, nothing moves
+        (Choice::LawyerUp, Choice::LawyerUp) => {
+            Ok((Outcome::MutualLawyerUp, 0, 0, 0, 0, 0, 0))
+        }
+    }
+}
+
+/// Scale a forfeit's snitch outcome by the configured forfeit penalty
+fn scale_forfeit_amount(
+    amount: u64,
+    config: &PayoffConfig,
+) -> Result<u64> {
+    let scaled = (amount as u128)
+        .checked_mul(config.forfeit_penalty_bps as u128)
+        .ok_or(SnitchError::Overflow)?
+        .checked_div(config.bps_denominator as u128)
+        .ok_or(SnitchError::Underflow)? as u64;
+    
+    Ok(scaled)
+}
+
+/// Match an optional account against the key recorded in the game state: it is
+/// required once recorded, and one supplied before then (created before the key
+/// was tracked) is recorded now
+fn check_registered(recorded: &mut Option<Pubkey>, supplied: Option<Pubkey>) -> Result<()> {
+    match (*recorded, supplied) {
+        (Some(key), Some(account)) => require_keys_eq!(account, key, SnitchError::Unauthorized),
+        (Some(_), None) => return err!(SnitchError::MissingAccount),
+        (None, account) => *recorded = account,
+    }
+    Ok(())
+}
+
+/// Withhold a timeout fee from the player's reward for this round, then from
+/// their unclaimed rewards. Returns (reward left, fee collected, part of the fee
+/// taken from unclaimed rewards)
+fn withhold_timeout_fee(
+    profile: &mut PlayerProfile,
+    reward: u64,
+    fee: u64,
+) -> (u64, u64, u64) {
+    let from_reward = fee.min(reward);
+    let from_pending = profile.deduct_pending_rewards(fee - from_reward);
+    (reward - from_reward, from_reward + from_pending, from_pending)
+}
+
+/// Settle the side pot by the round outcome. On a forfeit, winnings and
+/// bonuses are scaled by `forfeit_penalty_bps` like the round's rewards, and
+/// the unclaimed part of the loser's wager is refunded to them
+/// Returns (pot_to_a, pot_to_b, bonus_a, bonus_b, burned)
+fn calculate_side_pot(
+    outcome: Outcome,
+    side_pot_a: u64,
+    side_pot_b: u64,
+    yield_pool: u64,
+    forfeited: bool,
+    config: &PayoffConfig,
+) -> Result<(u64, u64, u64, u64, u64)> {
+    let total = side_pot_a
+        .checked_add(side_pot_b)
+        .ok_or(SnitchError::Overflow)?;
+    let scale = |amount: u64| -> Result<u64> {
+        if forfeited {
+            scale_forfeit_amount(amount, config)
+        } else {
+            Ok(amount)
+        }
+    };
+    
+    match outcome {
+        // Cooperators get their wagers back plus the cooperation yield on them
+        Outcome::MutualCooperation => {
+            let bonus_a = scale(calculate_yield_reward(
+                side_pot_a,
+                config.cooperation_yield_bps,
+                config.bps_denominator,
+            )?)?;
+            let bonus_b = scale(calculate_yield_reward(
+                side_pot_b,
+                config.cooperation_yield_bps,
+                config.bps_denominator,
+            )?)?;
+            
+            // Cap at available yield pool
+            let actual_bonus_a = bonus_a.min(yield_pool);
+            let actual_bonus_b = bonus_b.min(yield_pool - actual_bonus_a);
+            
+            Ok((side_pot_a, side_pot_b, actual_bonus_a, actual_bonus_b, 0))
+        }
+        
+        // The lone snitch takes the whole pot
+        Outcome::PlayerASnitched if !forfeited => Ok((total, 0, 0, 0, 0)),
+        Outcome::PlayerBSnitched if !forfeited => Ok((0, total, 0, 0, 0)),
+        Outcome::PlayerASnitched => {
+            let taken = scale(side_pot_b)?;
+            Ok((side_pot_a + taken, side_pot_b - taken, 0, 0, 0))
+        }
+        Outcome::PlayerBSnitched => {
+            let taken = scale(side_pot_a)?;
+            Ok((side_pot_a - taken, side_pot_b + taken, 0, 0, 0))
+        }
+        
+        // Mutual snitching burns it
+        Outcome::MutualSnitching => Ok((0, 0, 0, 0, total)),
+        
+        // Nobody betrayed anybody: wagers are refunded
+        _ => Ok((side_pot_a, side_pot_b, 0, 0, 0)),
     }
 }
+
+/// Net bribe result for one player: what they received minus what they paid
+fn bribe_pnl(received: u64, received_paid: bool, offered: u64, offered_paid: bool) -> Result<i64> {
+    let received = if received_paid { signed_amount(received)? } else { 0 };
+    let offered = if offered_paid { signed_amount(offered)? } else { 0 };
+    Ok(received
+        .checked_sub(offered)
+        .ok_or(SnitchError::Overflow)?)
+}
+
+/// Snitch reward against a lawyered-up victim, reduced by the lawyer's protection
+fn calculate_lawyered_snitch_reward(
+    victim_balance: u64,
+    config: &PayoffConfig,
+) -> Result<u64> {
+    let bps = config.bps_denominator as u128;
+    let unshielded_bps = bps
+        .checked_sub(config.lawyer_protection_bps as u128)
+        .ok_or(SnitchError::Underflow)?;
+    
+    let reward = (victim_balance as u128)
+        .checked_mul(config.snitch_reward_bps as u128)
+        .ok_or(SnitchError::Overflow)?
+        .checked_div(bps)
+        .ok_or(SnitchError::Underflow)?
+        .checked_mul(unshielded_bps)
+        .ok_or(SnitchError::Overflow)?
+        .checked_div(bps)
+        .ok_or(SnitchError::Underflow)? as u64;
+    
+    Ok(reward)
+}
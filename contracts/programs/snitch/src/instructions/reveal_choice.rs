@@ -1,18 +1,22 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer, Token, TokenAccount};
 use solana_program::clock::Clock;
 use solana_program::hash::hashv;
 
 use crate::state::*;
+use crate::utils::{calculate_lawyer_fee, signed_loss};
 use crate::SnitchError;
 use crate::ChoiceRevealed;
 
 #[derive(Accounts)]
 #[instruction(choice: u8, salt: [u8; 32])]
 pub struct RevealChoice<'info> {
+    /// Player wallet or their registered delegate
     #[account(mut)]
-    pub player: Signer<'info>,
+    pub signer: Signer<'info>,
     
     #[account(
+        mut,
         seeds = [b"game_state"],
         bump = game_state.bump,
     )]
@@ -26,11 +30,29 @@ pub struct RevealChoice<'info> {
     
     #[account(
         mut,
-        seeds = [b"player", player.key().as_ref()],
+        seeds = [b"player", player_profile.player.as_ref()],
         bump = player_profile.bump,
-        constraint = player_profile.player == player.key() @ SnitchError::Unauthorized,
     )]
     pub player_profile: Account<'info, PlayerProfile>,
+    
+    /// Player's associated token account (its balance sizes and pays the lawyer fee;
+    /// a delegate needs an SPL approval)
+    #[account(
+        mut,
+        associated_token::mint = game_state.token_mint,
+        associated_token::authority = player_profile.player,
+    )]
+    pub player_token: Account<'info, TokenAccount>,
+    
+    /// Yield vault receiving lawyer fees
+    #[account(
+        mut,
+        seeds = [b"yield_vault", game_state.key().as_ref()],
+        bump,
+    )]
+    pub yield_vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
 }
 
 pub fn handler(
@@ -38,7 +60,7 @@ pub fn handler(
     choice: u8,
     salt: [u8; 32],
 ) -> Result<()> {
-    let game_state = &ctx.accounts.game_state;
+    let game_state = &mut ctx.accounts.game_state;
     let interrogation = &mut ctx.accounts.interrogation;
     let clock = Clock::get()?;
     let now = clock.unix_timestamp;
@@ -64,8 +86,14 @@ pub fn handler(
         SnitchError::RevealPhaseEnded
     );
     
+    // Validate signer is the player or their active delegate
+    let player_key = ctx.accounts.player_profile.player;
+    ctx.accounts.player_profile.validate_player_or_delegate(
+        &ctx.accounts.signer.key(),
+        now,
+    )?;
+    
     // Validate player is in this round
-    let player_key = ctx.accounts.player.key();
     require!(
         interrogation.is_player_in_round(&player_key),
         SnitchError::PlayerNotInInterrogation
@@ -98,28 +126,48 @@ pub fn handler(
     );
     require!(valid, SnitchError::InvalidCommitment);
     
+    // Lawyering up costs a fee, paid to the yield pool at reveal
+    let lawyer_fee = if choice_enum == Choice::LawyerUp {
+        calculate_lawyer_fee(ctx.accounts.player_token.amount, &interrogation.payoff_config)?
+    } else {
+        0
+    };
+    
+    if lawyer_fee > 0 {
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.player_token.to_account_info(),
+                to: ctx.accounts.yield_vault.to_account_info(),
+                authority: ctx.accounts.signer.to_account_info(),
+            },
+        );
+        token::transfer(transfer_ctx, lawyer_fee)?;
+        
+        game_state.yield_pool = game_state.yield_pool
+            .checked_add(lawyer_fee)
+            .ok_or(SnitchError::Overflow)?;
+    }
+    
     // Store revealed choice
     if is_player_a {
         interrogation.choice_a = Some(choice_enum);
         interrogation.revealed_a = true;
+        interrogation.lawyer_fee_a = lawyer_fee;
+        interrogation.pnl_a.fees = interrogation.pnl_a.fees.saturating_add(lawyer_fee);
     } else {
         interrogation.choice_b = Some(choice_enum);
         interrogation.revealed_b = true;
+        interrogation.lawyer_fee_b = lawyer_fee;
+        interrogation.pnl_b.fees = interrogation.pnl_b.fees.saturating_add(lawyer_fee);
     }
     
     // Update player stats
     let player_profile = &mut ctx.accounts.player_profile;
-    match choice_enum {
-        Choice::Cooperate => {
-            player_profile.times_cooperated = player_profile.times_cooperated
-                .checked_add(1)
-                .ok_or(SnitchError::Overflow)?;
-        }
-        Choice::Snitch => {
-            player_profile.times_snitched = player_profile.times_snitched
-                .checked_add(1)
-                .ok_or(SnitchError::Overflow)?;
-        }
+    player_profile.record_choice(choice_enum)?;
+    if lawyer_fee > 0 {
+        player_profile.add_penalty(lawyer_fee)?;
+        player_profile.book_pnl(signed_loss(lawyer_fee)?)?;
     }
     
     // Update phase if both revealed
@@ -132,6 +180,7 @@ pub fn handler(
         round_id: interrogation.round_id,
         player: player_key,
         choice: choice_enum,
+        lawyer_fee,
         timestamp: now,
     });
     
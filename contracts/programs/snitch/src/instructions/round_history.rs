@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::SnitchError;
+
+#[derive(Accounts)]
+pub struct InitializeRoundHistory<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"game_state"],
+        bump = game_state.bump,
+        constraint = game_state.authority == admin.key() @ SnitchError::Unauthorized,
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = RoundHistory::SIZE,
+        seeds = [b"round_history", game_state.key().as_ref()],
+        bump,
+    )]
+    pub round_history: AccountLoader<'info, RoundHistory>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_round_history_handler(ctx: Context<InitializeRoundHistory>) -> Result<()> {
+    let mut round_history = ctx.accounts.round_history.load_init()?;
+    
+    round_history.game_state = ctx.accounts.game_state.key();
+    round_history.bump = ctx.bumps.round_history;
+    
+    // From now on every resolution must append to it
+    ctx.accounts.game_state.round_history = Some(ctx.accounts.round_history.key());
+    
+    msg!("Round history initialized: last {} rounds", RoundHistory::MAX_RECORDS);
+    
+    Ok(())
+}
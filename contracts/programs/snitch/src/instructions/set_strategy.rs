@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use solana_program::clock::Clock;
+
+use crate::state::*;
+use crate::instructions::close::restore_closed_profile;
+use crate::StrategySet;
+
+#[derive(Accounts)]
+#[instruction(strategy: Strategy)]
+pub struct SetStrategy<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    /// Player profile (init if needed)
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = PlayerProfile::SIZE,
+        seeds = [b"player", player.key().as_ref()],
+        bump,
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    /// CHECK: Tombstone of a previously closed profile, if any (restored and closed)
+    #[account(
+        mut,
+        seeds = [b"profile_tombstone", player.key().as_ref()],
+        bump,
+    )]
+    pub tombstone: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetStrategy>,
+    strategy: Strategy,
+) -> Result<()> {
+    strategy.validate()?;
+    
+    let player_key = ctx.accounts.player.key();
+    let player_profile = &mut ctx.accounts.player_profile;
+    let now = Clock::get()?.unix_timestamp;
+    
+    player_profile.player = player_key;
+    player_profile.bump = ctx.bumps.player_profile;
+    restore_closed_profile(
+        player_profile,
+        &ctx.accounts.tombstone.to_account_info(),
+        &ctx.accounts.player.to_account_info(),
+    )?;
+    player_profile.migrate();
+    player_profile.strategy = strategy;
+    
+    emit!(StrategySet {
+        player: player_key,
+        strategy,
+        timestamp: now,
+    });
+    
+    msg!("Player {} set autopilot strategy {:?}", player_key, strategy);
+    
+    Ok(())
+}
@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer, Token, TokenAccount};
+use solana_program::clock::Clock;
+
+use crate::state::*;
+use crate::utils::signed_loss;
+use crate::SnitchError;
+use crate::ChoiceCommitted;
+
+#[derive(Accounts)]
+#[instruction(commitment: [u8; 32])]
+pub struct UpdateCommitment<'info> {
+    /// Player wallet or their registered delegate
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"game_state"],
+        bump = game_state.bump,
+    )]
+    pub game_state: Account<'info, GameState>,
+    
+    #[account(
+        mut,
+        constraint = interrogation.round_id == player_profile.current_round @ SnitchError::PlayerNotInInterrogation,
+    )]
+    pub interrogation: Account<'info, Interrogation>,
+    
+    #[account(
+        mut,
+        seeds = [b"player", player_profile.player.as_ref()],
+        bump = player_profile.bump,
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    /// Player's token account (pays the revision fee; a delegate needs an SPL approval)
+    #[account(
+        mut,
+        constraint = player_token.owner == player_profile.player @ SnitchError::Unauthorized,
+        constraint = player_token.mint == game_state.token_mint @ SnitchError::Unauthorized,
+    )]
+    pub player_token: Account<'info, TokenAccount>,
+    
+    /// Yield vault receiving revision fees
+    #[account(
+        mut,
+        seeds = [b"yield_vault", game_state.key().as_ref()],
+        bump,
+    )]
+    pub yield_vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateCommitment>,
+    commitment: [u8; 32],
+) -> Result<()> {
+    let game_state = &mut ctx.accounts.game_state;
+    let interrogation = &mut ctx.accounts.interrogation;
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    
+    // Validate game state
+    game_state.validate_not_paused()?;
+    
+    // Revisions are allowed until the commit deadline
+    require!(
+        interrogation.can_commit(now),
+        SnitchError::CommitPhaseEnded
+    );
+    
+    // Validate signer is the player or their active delegate
+    let player_key = ctx.accounts.player_profile.player;
+    ctx.accounts.player_profile.validate_player_or_delegate(
+        &ctx.accounts.signer.key(),
+        now,
+    )?;
+    
+    // Validate player is in this round
+    require!(
+        interrogation.is_player_in_round(&player_key),
+        SnitchError::PlayerNotInInterrogation
+    );
+    
+    // Replace the existing commitment
+    let is_player_a = interrogation.player_a == player_key;
+    
+    let revision = if is_player_a {
+        require!(
+            interrogation.commitment_a.is_some(),
+            SnitchError::NotCommitted
+        );
+        interrogation.commitment_a = Some(commitment);
+        interrogation.revisions_a = interrogation.revisions_a
+            .checked_add(1)
+            .ok_or(SnitchError::Overflow)?;
+        interrogation.revisions_a
+    } else {
+        require!(
+            interrogation.commitment_b.is_some(),
+            SnitchError::NotCommitted
+        );
+        interrogation.commitment_b = Some(commitment);
+        interrogation.revisions_b = interrogation.revisions_b
+            .checked_add(1)
+            .ok_or(SnitchError::Overflow)?;
+        interrogation.revisions_b
+    };
+    
+    // Changing your mind costs the fee in force at selection, paid to the yield pool
+    let fee = interrogation.payoff_config.commitment_revision_fee;
+    if fee > 0 {
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.player_token.to_account_info(),
+                to: ctx.accounts.yield_vault.to_account_info(),
+                authority: ctx.accounts.signer.to_account_info(),
+            },
+        );
+        token::transfer(transfer_ctx, fee)?;
+        
+        game_state.yield_pool = game_state.yield_pool
+            .checked_add(fee)
+            .ok_or(SnitchError::Overflow)?;
+        ctx.accounts.player_profile.add_penalty(fee)?;
+        ctx.accounts.player_profile.book_pnl(signed_loss(fee)?)?;
+        
+        let pnl = if is_player_a { &mut interrogation.pnl_a } else { &mut interrogation.pnl_b };
+        pnl.fees = pnl.fees.saturating_add(fee);
+    }
+    
+    // Emit event
+    emit!(ChoiceCommitted {
+        round_id: interrogation.round_id,
+        player: player_key,
+        commitment,
+        side_wager: 0,
+        side_pot_a: interrogation.side_pot_a,
+        side_pot_b: interrogation.side_pot_b,
+        revision,
+        timestamp: now,
+    });
+    
+    msg!("Player {} revised commitment (revision {}) in round {}", 
+        player_key, revision, interrogation.round_id);
+    
+    Ok(())
+}
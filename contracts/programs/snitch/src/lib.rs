@@ -22,7 +22,7 @@ pub mod snitch {
         instructions::initialize::handler(ctx, config)
     }
 
-    /// Select 2 random players for interrogation (admin or VRF)
+    /// Select 2 random players for interrogation (selector or VRF)
     pub fn select_players(
         ctx: Context<SelectPlayers>,
         random_seed: [u8; 32],
@@ -30,12 +30,38 @@ pub mod snitch {
         instructions::select_players::handler(ctx, random_seed)
     }
 
-    /// Commit a choice (COOPERATE or SNITCH) using salted hash
+    /// Publicly pledge a choice before committing
+    pub fn pledge(
+        ctx: Context<Pledge>,
+        choice: u8,
+    ) -> Result<()> {
+        instructions::pledge::handler(ctx, choice)
+    }
+
+    /// Post a message to the interrogation room (selected players only)
+    pub fn post_message(
+        ctx: Context<PostMessage>,
+        text: String,
+    ) -> Result<()> {
+        instructions::post_message::handler(ctx, text)
+    }
+
+    /// Commit a choice (COOPERATE, SNITCH or LAWYER_UP) using salted hash,
+    /// optionally doubling down with a side wager into the round's side pot
     pub fn commit_choice(
         ctx: Context<CommitChoice>,
         commitment: [u8; 32],
+        side_wager: u64,
     ) -> Result<()> {
-        instructions::commit_choice::handler(ctx, commitment)
+        instructions::commit_choice::handler(ctx, commitment, side_wager)
+    }
+
+    /// Replace an existing commitment before the commit deadline
+    pub fn update_commitment(
+        ctx: Context<UpdateCommitment>,
+        commitment: [u8; 32],
+    ) -> Result<()> {
+        instructions::update_commitment::handler(ctx, commitment)
     }
 
     /// Reveal the committed choice with salt verification
@@ -47,6 +73,14 @@ pub mod snitch {
         instructions::reveal_choice::handler(ctx, choice, salt)
     }
 
+    /// Escrow a bribe payable to the opponent if they reveal COOPERATE
+    pub fn offer_bribe(
+        ctx: Context<OfferBribe>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::offer_bribe::handler(ctx, amount)
+    }
+
     /// Resolve the round after both reveals or timeout
     pub fn resolve_round(
         ctx: Context<ResolveRound>,
@@ -54,6 +88,13 @@ pub mod snitch {
         instructions::resolve_round::handler(ctx)
     }
 
+    /// Forfeit the round: resolve now as a lone cooperator against a snitch
+    pub fn forfeit(
+        ctx: Context<ResolveRound>,
+    ) -> Result<()> {
+        instructions::forfeit::handler(ctx)
+    }
+
     /// Claim pending rewards
     pub fn claim_rewards(
         ctx: Context<ClaimRewards>,
@@ -61,7 +102,39 @@ pub mod snitch {
         instructions::claim_rewards::handler(ctx)
     }
 
-    /// Update game config (admin only)
+    /// Register a session key that may commit/reveal until expiry
+    pub fn set_delegate(
+        ctx: Context<SetDelegate>,
+        delegate: Pubkey,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::delegate::set_delegate_handler(ctx, delegate, expires_at)
+    }
+
+    /// Revoke the registered session key
+    pub fn revoke_delegate(
+        ctx: Context<RevokeDelegate>,
+    ) -> Result<()> {
+        instructions::delegate::revoke_delegate_handler(ctx)
+    }
+
+    /// Store an autopilot strategy applied when the player fails to commit
+    pub fn set_strategy(
+        ctx: Context<SetStrategy>,
+        strategy: Strategy,
+    ) -> Result<()> {
+        instructions::set_strategy::handler(ctx, strategy)
+    }
+
+    /// Mint an earned milestone badge as a soulbound token
+    pub fn mint_badge(
+        ctx: Context<MintBadge>,
+        badge: Badge,
+    ) -> Result<()> {
+        instructions::mint_badge::handler(ctx, badge)
+    }
+
+    /// Propose a game config, applied after the timelock (authority or config manager)
     pub fn update_config(
         ctx: Context<UpdateConfig>,
         config: GameConfig,
@@ -69,27 +142,211 @@ pub mod snitch {
         instructions::update_config::handler(ctx, config)
     }
 
-    /// Emergency pause (admin only)
-    pub fn pause(
+    /// Apply the pending config once its timelock has passed (anyone)
+    pub fn apply_config(
+        ctx: Context<ApplyConfig>,
+    ) -> Result<()> {
+        instructions::admin::apply_config_handler(ctx)
+    }
+
+    /// Drop the pending config (authority or config manager)
+    pub fn cancel_config(
+        ctx: Context<UpdateConfig>,
+    ) -> Result<()> {
+        instructions::admin::cancel_config_handler(ctx)
+    }
+
+    /// Raise the delay between proposing and applying a config, up to 30 days (admin only)
+    pub fn set_config_timelock(
         ctx: Context<AdminOnly>,
+        timelock: i64,
+    ) -> Result<()> {
+        instructions::admin::set_config_timelock_handler(ctx, timelock)
+    }
+
+    /// Configure the built-in M-of-N signer set (admin only, once).
+    /// From then on admin actions, config changes, resuming, cooldown resets and
+    /// round cancellations go through multisig proposals instead of single keys;
+    /// only pausing stays single-key for emergencies.
+    pub fn set_multisig(
+        ctx: Context<AdminOnly>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::multisig::set_multisig_handler(ctx, members, threshold)
+    }
+
+    /// Propose an admin action to the multisig (members only)
+    pub fn create_multisig_proposal(
+        ctx: Context<CreateMultisigProposal>,
+        action: MultisigAction,
+    ) -> Result<()> {
+        instructions::multisig::create_multisig_proposal_handler(ctx, action)
+    }
+
+    /// Approve a multisig proposal (members only)
+    pub fn approve_multisig_proposal(
+        ctx: Context<ApproveMultisigProposal>,
+    ) -> Result<()> {
+        instructions::multisig::approve_multisig_proposal_handler(ctx)
+    }
+
+    /// Execute a multisig proposal once its threshold is met (anyone)
+    pub fn execute_multisig_proposal(
+        ctx: Context<ExecuteMultisigProposal>,
+    ) -> Result<()> {
+        instructions::multisig::execute_multisig_proposal_handler(ctx)
+    }
+
+    /// Withdraw an unexecuted multisig proposal, refunding its rent (proposer only)
+    pub fn cancel_multisig_proposal(
+        ctx: Context<CancelMultisigProposal>,
+    ) -> Result<()> {
+        instructions::multisig::cancel_multisig_proposal_handler(ctx)
+    }
+
+    /// Propose a new game config for holders to vote on
+    pub fn create_governance_proposal(
+        ctx: Context<CreateGovernanceProposal>,
+        config: GameConfig,
+    ) -> Result<()> {
+        instructions::governance::create_governance_proposal_handler(ctx, config)
+    }
+
+    /// Vote on a governance proposal, locking `amount` tokens as weight
+    pub fn cast_vote(
+        ctx: Context<CastVote>,
+        amount: u64,
+        support: bool,
+    ) -> Result<()> {
+        instructions::governance::cast_vote_handler(ctx, amount, support)
+    }
+
+    /// Unlock voted tokens once the voting window has closed
+    pub fn withdraw_vote(
+        ctx: Context<WithdrawVote>,
+    ) -> Result<()> {
+        instructions::governance::withdraw_vote_handler(ctx)
+    }
+
+    /// Send a passed proposal into the timelocked config update (anyone)
+    pub fn execute_governance_proposal(
+        ctx: Context<ExecuteGovernanceProposal>,
+    ) -> Result<()> {
+        instructions::governance::execute_governance_proposal_handler(ctx)
+    }
+
+    /// Close a settled proposal and its vote escrow once every vote is withdrawn (anyone)
+    pub fn close_governance_proposal(
+        ctx: Context<CloseGovernanceProposal>,
+    ) -> Result<()> {
+        instructions::governance::close_governance_proposal_handler(ctx)
+    }
+
+    /// Set the governance voting window and non-zero quorum (admin only)
+    pub fn set_governance(
+        ctx: Context<AdminOnly>,
+        voting_period: i64,
+        quorum: u64,
+    ) -> Result<()> {
+        instructions::governance::set_governance_handler(ctx, voting_period, quorum)
+    }
+
+    /// Create the on-chain leaderboard (admin only, once)
+    pub fn initialize_leaderboard(
+        ctx: Context<InitializeLeaderboard>,
+    ) -> Result<()> {
+        instructions::leaderboard::initialize_leaderboard_handler(ctx)
+    }
+
+    /// Create the recent round history ring buffer (admin only, once)
+    pub fn initialize_round_history(
+        ctx: Context<InitializeRoundHistory>,
+    ) -> Result<()> {
+        instructions::round_history::initialize_round_history_handler(ctx)
+    }
+
+    /// Emergency pause (authority or pauser)
+    pub fn pause(
+        ctx: Context<PauserOnly>,
     ) -> Result<()> {
         instructions::admin::pause_handler(ctx)
     }
 
-    /// Resume game (admin only)
+    /// Resume game (authority or pauser; a multisig proposal once the multisig is on)
     pub fn resume(
-        ctx: Context<AdminOnly>,
+        ctx: Context<PauserOnly>,
     ) -> Result<()> {
         instructions::admin::resume_handler(ctx)
     }
 
-    /// Update player cooldown manually (admin only, for emergencies)
+    /// Abort the active round and refund its escrows (authority or treasury, multisig off only)
+    pub fn cancel_round(
+        ctx: Context<CancelRound>,
+    ) -> Result<()> {
+        instructions::cancel_round::handler(ctx)
+    }
+
+    /// Update player cooldown manually (authority or selector, for emergencies)
     pub fn reset_cooldown(
         ctx: Context<ResetCooldown>,
         player: Pubkey,
     ) -> Result<()> {
         instructions::admin::reset_cooldown_handler(ctx, player)
     }
+
+    /// Propose a new authority (admin only); takes effect once accepted
+    pub fn propose_authority(
+        ctx: Context<AdminOnly>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::admin::propose_authority_handler(ctx, new_authority)
+    }
+
+    /// Accept a pending authority transfer (signed by the proposed authority)
+    pub fn accept_authority(
+        ctx: Context<AcceptAuthority>,
+    ) -> Result<()> {
+        instructions::admin::accept_authority_handler(ctx)
+    }
+
+    /// Assign or revoke (`None`) a delegated admin role (admin only)
+    pub fn assign_role(
+        ctx: Context<AdminOnly>,
+        role: Role,
+        holder: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::admin::assign_role_handler(ctx, role, holder)
+    }
+
+    /// Realloc a player profile to the current layout and fill new defaults
+    pub fn migrate_profile(
+        ctx: Context<MigrateProfile>,
+    ) -> Result<()> {
+        instructions::migrate::migrate_profile_handler(ctx)
+    }
+
+    /// Realloc the game state to the current layout and fill new defaults
+    pub fn migrate_game_state(
+        ctx: Context<MigrateGameState>,
+    ) -> Result<()> {
+        instructions::migrate::migrate_game_state_handler(ctx)
+    }
+
+    /// Close a finished round after its retention window, refunding rent to the payer
+    pub fn close_interrogation(
+        ctx: Context<CloseInterrogation>,
+    ) -> Result<()> {
+        instructions::close::close_interrogation_handler(ctx)
+    }
+
+    /// Close the caller's profile (no pending rewards, not in a round or on cooldown).
+    /// Its record is kept in a tombstone and restored if the profile is re-created.
+    pub fn close_profile(
+        ctx: Context<CloseProfile>,
+    ) -> Result<()> {
+        instructions::close::close_profile_handler(ctx)
+    }
 }
 
 #[error_code]
@@ -110,7 +367,7 @@ pub enum SnitchError {
     NotCommitted,
     #[msg("Invalid commitment")]
     InvalidCommitment,
-    #[msg("Invalid choice - must be 0 (COOPERATE) or 1 (SNITCH)")]
+    #[msg("Invalid choice - must be 0 (COOPERATE), 1 (SNITCH) or 2 (LAWYER_UP)")]
     InvalidChoice,
     #[msg("Commit-reveal phase has ended")]
     CommitPhaseEnded,
@@ -152,6 +409,60 @@ pub enum SnitchError {
     TransferFailed,
     #[msg("Token burn failed")]
     BurnFailed,
+    #[msg("Amount must be greater than zero")]
+    InvalidAmount,
+    #[msg("Player already pledged")]
+    AlreadyPledged,
+    #[msg("Message is too long")]
+    MessageTooLong,
+    #[msg("Message log is full")]
+    MessageLogFull,
+    #[msg("Invalid delegate")]
+    InvalidDelegate,
+    #[msg("Invalid autopilot strategy")]
+    InvalidStrategy,
+    #[msg("Badge has not been earned")]
+    BadgeNotEarned,
+    #[msg("Badge already minted")]
+    BadgeAlreadyMinted,
+    #[msg("Round is still within its retention window")]
+    RetentionPeriodActive,
+    #[msg("Claim pending rewards before closing the profile")]
+    PendingRewardsUnclaimed,
+    #[msg("No pending config")]
+    NoPendingConfig,
+    #[msg("Pending config is still timelocked")]
+    ConfigTimelockActive,
+    #[msg("Invalid multisig signer set")]
+    InvalidMultisig,
+    #[msg("Signer is not a multisig member")]
+    NotMultisigMember,
+    #[msg("Member already approved this proposal")]
+    AlreadyApproved,
+    #[msg("Proposal has not reached its threshold")]
+    ThresholdNotMet,
+    #[msg("Proposal already executed")]
+    ProposalAlreadyExecuted,
+    #[msg("Proposal was created under a previous signer set")]
+    StaleProposal,
+    #[msg("Account required by this action is missing")]
+    MissingAccount,
+    #[msg("Voting window is closed")]
+    VotingClosed,
+    #[msg("Voting window is still open")]
+    VotingActive,
+    #[msg("Proposal did not pass")]
+    ProposalRejected,
+    #[msg("A config is already pending; cancel it first")]
+    ConfigPending,
+    #[msg("Action must go through a multisig proposal")]
+    MultisigRequired,
+    #[msg("Proposal execution window has passed")]
+    ProposalExpired,
+    #[msg("Proposal passed and can still be executed")]
+    ProposalExecutable,
+    #[msg("Votes are still locked in the proposal")]
+    VotesOutstanding,
 }
 
 #[event]
@@ -159,6 +470,25 @@ pub struct RoundStarted {
     pub round_id: u64,
     pub player_a: Pubkey,
     pub player_b: Pubkey,
+    pub trust_score_a: u16,
+    pub trust_score_b: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PledgeMade {
+    pub round_id: u64,
+    pub player: Pubkey,
+    pub pledge: Choice,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MessagePosted {
+    pub round_id: u64,
+    pub sender: Pubkey,
+    pub index: u8,
+    pub text: String,
     pub timestamp: i64,
 }
 
@@ -167,6 +497,10 @@ pub struct ChoiceCommitted {
     pub round_id: u64,
     pub player: Pubkey,
     pub commitment: [u8; 32],
+    pub side_wager: u64,
+    pub side_pot_a: u64,
+    pub side_pot_b: u64,
+    pub revision: u16,
     pub timestamp: i64,
 }
 
@@ -175,6 +509,7 @@ pub struct ChoiceRevealed {
     pub round_id: u64,
     pub player: Pubkey,
     pub choice: Choice,
+    pub lawyer_fee: u64,
     pub timestamp: i64,
 }
 
@@ -189,6 +524,52 @@ pub struct RoundResolved {
     pub player_a_reward: u64,
     pub player_b_reward: u64,
     pub amount_burned: u64,
+    pub lawyer_fee_a: u64,
+    pub lawyer_fee_b: u64,
+    pub side_pot_a: u64,
+    pub side_pot_b: u64,
+    pub side_pot_payout_a: u64,
+    pub side_pot_payout_b: u64,
+    pub side_pot_burned: u64,
+    pub bribe_a: u64,
+    pub bribe_b: u64,
+    pub bribe_a_paid: bool,
+    pub bribe_b_paid: bool,
+    pub promise_broken_a: bool,
+    pub promise_broken_b: bool,
+    pub autopilot_a: Option<Strategy>,
+    pub autopilot_b: Option<Strategy>,
+    pub forfeited_by: Option<Pubkey>,
+    pub trust_score_a: u16,
+    pub trust_score_b: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BribeOffered {
+    pub round_id: u64,
+    pub briber: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub total: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PlayerForfeited {
+    pub round_id: u64,
+    pub player: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoundCancelled {
+    pub round_id: u64,
+    pub player_a: Pubkey,
+    pub player_b: Pubkey,
+    pub refunded_a: u64,
+    pub refunded_b: u64,
+    pub cancelled_by: Pubkey,
     pub timestamp: i64,
 }
 
@@ -199,9 +580,180 @@ pub struct RewardsClaimed {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct DelegateSet {
+    pub player: Pubkey,
+    pub delegate: Pubkey,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DelegateRevoked {
+    pub player: Pubkey,
+    pub delegate: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StrategySet {
+    pub player: Pubkey,
+    pub strategy: Strategy,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BadgeMinted {
+    pub player: Pubkey,
+    pub badge: Badge,
+    pub mint: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct PlayerSelected {
     pub player: Pubkey,
     pub round_id: u64,
     pub timestamp: i64,
 }
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InterrogationClosed {
+    pub round_id: u64,
+    pub payer: Pubkey,
+    pub closed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProfileClosed {
+    pub player: Pubkey,
+    pub rent_payer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleAssigned {
+    pub role: Role,
+    pub previous_holder: Option<Pubkey>,
+    pub holder: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigProposed {
+    pub proposed_by: Pubkey,
+    pub config: GameConfig,
+    pub activates_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigApplied {
+    pub config: GameConfig,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigCancelled {
+    pub cancelled_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigUpdated {
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub signer_set_seq: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigProposalCreated {
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub action: MultisigAction,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigProposalApproved {
+    pub proposal_id: u64,
+    pub member: Pubkey,
+    pub approvals: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigProposalExecuted {
+    pub proposal_id: u64,
+    pub executed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GovernanceProposalCreated {
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub config: GameConfig,
+    pub voting_ends_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoteCast {
+    pub proposal_id: u64,
+    pub voter: Pubkey,
+    pub amount: u64,
+    pub support: bool,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoteWithdrawn {
+    pub proposal_id: u64,
+    pub voter: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GovernanceProposalExecuted {
+    pub proposal_id: u64,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub executed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GovernanceProposalClosed {
+    pub proposal_id: u64,
+    pub executed: bool,
+    pub closed_by: Pubkey,
+    pub timestamp: i64,
+}
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount, Mint};
+use anchor_spl::token::Mint;
 
 /// Game configuration parameters
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
@@ -26,6 +26,18 @@ pub struct GameConfig {
     pub use_vrf: bool,
     /// Authorized VRF oracle (if use_vrf is true)
     pub vrf_oracle: Option<Pubkey>,
+    /// Fee paid to the yield pool for lawyering up (2% = 200 basis points)
+    pub lawyer_fee_bps: u16,
+    /// Portion of the snitch reward a lawyer shields (50% = 5000 basis points)
+    pub lawyer_protection_bps: u16,
+    /// Flat fee paid to the yield pool per commitment revision (0 = free)
+    pub commitment_revision_fee: u64,
+    /// Share of the snitch outcome applied to a forfeiting player (100% = 10000 basis points)
+    pub forfeit_penalty_bps: u16,
+    /// Extra cooperation yield per consecutive prior cooperation, as a share of the base yield (0 = off)
+    pub streak_bonus_bps: u16,
+    /// Cap on the streak bonus (100% = yield doubled)
+    pub max_streak_bonus_bps: u16,
 }
 
 impl GameConfig {
@@ -34,6 +46,10 @@ impl GameConfig {
         require!(self.cooperation_yield_bps <= 10000, SnitchError::InvalidConfig);
         require!(self.snitch_reward_bps <= 10000, SnitchError::InvalidConfig);
         require!(self.double_snitch_penalty_bps <= 10000, SnitchError::InvalidConfig);
+        require!(self.lawyer_fee_bps <= 10000, SnitchError::InvalidConfig);
+        require!(self.lawyer_protection_bps <= 10000, SnitchError::InvalidConfig);
+        require!(self.forfeit_penalty_bps <= 10000, SnitchError::InvalidConfig);
+        require!(self.max_streak_bonus_bps <= 10000, SnitchError::InvalidConfig);
         require!(self.transfer_tax_bps <= 1000, SnitchError::InvalidConfig); // Max 10%
         require!(self.commit_duration > 0, SnitchError::InvalidConfig);
         require!(self.reveal_duration > 0, SnitchError::InvalidConfig);
@@ -58,6 +74,70 @@ impl GameConfig {
             bps_denominator: 10000,
             use_vrf: false,
             vrf_oracle: None,
+            lawyer_fee_bps: 200,          // 2%
+            lawyer_protection_bps: 5000,  // 50%
+            commitment_revision_fee: 0,
+            forfeit_penalty_bps: 10000,   // 100%
+            streak_bonus_bps: 0,
+            max_streak_bonus_bps: 0,
+        }
+    }
+}
+
+/// Payoff-relevant config, snapshotted into each round at selection
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PayoffConfig {
+    pub cooperation_yield_bps: u16,
+    pub snitch_reward_bps: u16,
+    pub double_snitch_penalty_bps: u16,
+    pub lawyer_fee_bps: u16,
+    pub lawyer_protection_bps: u16,
+    pub commitment_revision_fee: u64,
+    pub forfeit_penalty_bps: u16,
+    pub streak_bonus_bps: u16,
+    pub max_streak_bonus_bps: u16,
+    pub bps_denominator: u16,
+}
+
+impl PayoffConfig {
+    pub const SIZE: usize = 
+        2 +   // cooperation_yield_bps
+        2 +   // snitch_reward_bps
+        2 +   // double_snitch_penalty_bps
+        2 +   // lawyer_fee_bps
+        2 +   // lawyer_protection_bps
+        8 +   // commitment_revision_fee
+        2 +   // forfeit_penalty_bps
+        2 +   // streak_bonus_bps
+        2 +   // max_streak_bonus_bps
+        2;    // bps_denominator
+
+    /// Cooperation yield boosted by the player's current cooperation streak
+    pub fn streak_yield_bps(&self, cooperation_streak: u32) -> u64 {
+        let bonus_bps = (cooperation_streak as u64)
+            .saturating_mul(self.streak_bonus_bps as u64)
+            .min(self.max_streak_bonus_bps as u64);
+        let bps = self.bps_denominator as u64;
+        (self.cooperation_yield_bps as u64)
+            .saturating_mul(bps + bonus_bps)
+            .checked_div(bps)
+            .unwrap_or(0)
+    }
+}
+
+impl From<&GameConfig> for PayoffConfig {
+    fn from(config: &GameConfig) -> Self {
+        Self {
+            cooperation_yield_bps: config.cooperation_yield_bps,
+            snitch_reward_bps: config.snitch_reward_bps,
+            double_snitch_penalty_bps: config.double_snitch_penalty_bps,
+            lawyer_fee_bps: config.lawyer_fee_bps,
+            lawyer_protection_bps: config.lawyer_protection_bps,
+            commitment_revision_fee: config.commitment_revision_fee,
+            forfeit_penalty_bps: config.forfeit_penalty_bps,
+            streak_bonus_bps: config.streak_bonus_bps,
+            max_streak_bonus_bps: config.max_streak_bonus_bps,
+            bps_denominator: config.bps_denominator,
         }
     }
 }
@@ -65,6 +145,8 @@ impl GameConfig {
 /// Global game state PDA
 #[account]
 pub struct GameState {
+    /// Layout version (first field so it sits at a fixed offset)
+    pub version: u8,
     /// Authority/admin of the game
     pub authority: Pubkey,
     /// The SNITCH token mint
@@ -89,11 +171,30 @@ pub struct GameState {
     pub bump: u8,
     /// Reserved space for future upgrades
     pub reserved: [u8; 32],
+    /// Authority proposed via propose_authority, awaiting acceptance
+    pub pending_authority: Option<Pubkey>,
+    /// Delegated admin roles (the authority implicitly holds all of them)
+    pub roles: Roles,
+    /// Config proposed via update_config, applied by apply_config after the timelock
+    pub pending_config: Option<GameConfig>,
+    /// When the pending config may be applied
+    pub pending_config_activates_at: i64,
+    /// Delay between proposing and applying a config (seconds)
+    pub config_timelock: i64,
+    /// Built-in M-of-N signer set for admin actions
+    pub multisig: Multisig,
+    /// Holder governance parameters
+    pub governance: Governance,
+    /// Leaderboard account, once initialized (resolve_round must then update it)
+    pub leaderboard: Option<Pubkey>,
+    /// Round history account, once initialized (resolve_round must then append to it)
+    pub round_history: Option<Pubkey>,
 }
 
 impl GameState {
     pub const SIZE: usize = 
         8 +  // discriminator
+        1 +  // version
         32 + // authority
         32 + // token_mint
         8 +  // current_round
@@ -105,8 +206,101 @@ impl GameState {
         8 +  // total_burned
         8 +  // total_rewards_distributed
         1 +  // bump
+        32 + // reserved
+        33 + // pending_authority (Option<Pubkey>)
+        Roles::SIZE + // roles
+        1 + GameConfig::SIZE + // pending_config (Option<GameConfig>)
+        8 +  // pending_config_activates_at
+        8 +  // config_timelock
+        Multisig::SIZE + // multisig
+        Governance::SIZE + // governance
+        33 + // leaderboard (Option<Pubkey>)
+        33;  // round_history (Option<Pubkey>)
+
+    /// Size of the pre-versioning layout, the only one without a version byte
+    pub const LEGACY_SIZE: usize = 
+        8 +  // discriminator
+        32 + // authority
+        32 + // token_mint
+        8 +  // current_round
+        8 +  // yield_pool
+        GameConfig::LEGACY_SIZE + // config
+        1 +  // paused
+        33 + // active_interrogation (Option<Pubkey>)
+        8 +  // total_rounds_completed
+        8 +  // total_burned
+        8 +  // total_rewards_distributed
+        1 +  // bump
         32;  // reserved
 
+    /// Current layout version. New fields are appended at the end and decoded
+    /// only from accounts whose version has them (see `try_deserialize_any_version`);
+    /// `migrate` then fills their defaults.
+    pub const CURRENT_VERSION: u8 = 8;
+
+    /// Config timelock for new and migrated games
+    pub const DEFAULT_CONFIG_TIMELOCK: i64 = 86400; // 1 day
+    /// Longest config timelock, so raising it can't freeze config changes for good
+    pub const MAX_CONFIG_TIMELOCK: i64 = 30 * 86400; // 30 days
+
+    /// Fill defaults for fields added since the account's version.
+    /// Returns false if the account was already current.
+    pub fn migrate(&mut self) -> bool {
+        if self.version >= Self::CURRENT_VERSION {
+            return false;
+        }
+        // v1: version byte introduced (legacy configs get their new fields in `GameConfig::read_legacy`)
+        // v2: pending_authority, defaults to None
+        // v3: roles, default is unassigned
+        // v4: pending config (None) and the config timelock
+        if self.version < 4 {
+            self.config_timelock = Self::DEFAULT_CONFIG_TIMELOCK;
+        }
+        // v5: multisig, default is an empty (disabled) signer set
+        // v6: governance, with the default voting window and quorum
+        if self.version < 6 {
+            self.governance.voting_period = Governance::DEFAULT_VOTING_PERIOD;
+            self.governance.quorum = Governance::default_quorum(&self.config);
+        }
+        // v7: leaderboard key, None until resolve_round sees an existing leaderboard
+        // v8: round history key, likewise
+        self.version = Self::CURRENT_VERSION;
+        true
+    }
+
+    /// Decode a game state written by any layout version. Fields the account's
+    /// version predates come back as defaults, whatever bytes follow its data.
+    /// The pre-versioning layout is recognised by its size.
+    pub fn try_deserialize_any_version(data: &[u8]) -> Result<Self> {
+        let legacy = data.len() == Self::LEGACY_SIZE;
+        let buf = &mut strip_discriminator::<Self>(data)?;
+        let version: u8 = if legacy { 0 } else { read_field(buf)? };
+        Ok(Self {
+            version,
+            authority: read_field(buf)?,
+            token_mint: read_field(buf)?,
+            current_round: read_field(buf)?,
+            yield_pool: read_field(buf)?,
+            config: if legacy { GameConfig::read_legacy(buf)? } else { read_field(buf)? },
+            paused: read_field(buf)?,
+            active_interrogation: read_field(buf)?,
+            total_rounds_completed: read_field(buf)?,
+            total_burned: read_field(buf)?,
+            total_rewards_distributed: read_field(buf)?,
+            bump: read_field(buf)?,
+            reserved: read_field(buf)?,
+            pending_authority: read_since(buf, version, 2)?,
+            roles: read_since(buf, version, 3)?,
+            pending_config: read_since(buf, version, 4)?,
+            pending_config_activates_at: read_since(buf, version, 4)?,
+            config_timelock: read_since(buf, version, 4)?,
+            multisig: read_since(buf, version, 5)?,
+            governance: read_since(buf, version, 6)?,
+            leaderboard: read_since(buf, version, 7)?,
+            round_history: read_since(buf, version, 8)?,
+        })
+    }
+
     pub fn validate_authority(&self, signer: &Signer) -> Result<()> {
         require!(
             self.authority == signer.key(),
@@ -115,6 +309,52 @@ impl GameState {
         Ok(())
     }
 
+    /// The authority holds every role; anyone else only the role assigned to them
+    pub fn has_role(&self, role: Role, key: &Pubkey) -> bool {
+        self.authority == *key || self.roles.holder(role) == Some(*key)
+    }
+
+    pub fn validate_role(&self, role: Role, signer: &Signer) -> Result<()> {
+        require!(
+            self.has_role(role, &signer.key()),
+            SnitchError::Unauthorized
+        );
+        Ok(())
+    }
+
+    /// Stage `config` to apply once the timelock has passed.
+    /// A config already pending must be cancelled first, never silently replaced.
+    pub fn propose_config(&mut self, config: GameConfig, now: i64) -> Result<i64> {
+        require!(self.pending_config.is_none(), SnitchError::ConfigPending);
+        config.validate()?;
+        let activates_at = now
+            .checked_add(self.config_timelock)
+            .ok_or(SnitchError::Overflow)?;
+        self.pending_config = Some(config);
+        self.pending_config_activates_at = activates_at;
+        Ok(activates_at)
+    }
+
+    /// The timelock can only be raised, up to `MAX_CONFIG_TIMELOCK`
+    pub fn validate_config_timelock(&self, timelock: i64) -> Result<()> {
+        require!(timelock >= self.config_timelock, SnitchError::InvalidConfig);
+        require!(timelock <= Self::MAX_CONFIG_TIMELOCK, SnitchError::InvalidConfig);
+        Ok(())
+    }
+
+    pub fn set_config_timelock(&mut self, timelock: i64) -> Result<()> {
+        self.validate_config_timelock(timelock)?;
+        self.config_timelock = timelock;
+        Ok(())
+    }
+
+    /// Stage an authority transfer, completed when the new authority accepts
+    pub fn propose_authority(&mut self, new_authority: Pubkey) -> Result<()> {
+        require!(new_authority != self.authority, SnitchError::InvalidConfig);
+        self.pending_authority = Some(new_authority);
+        Ok(())
+    }
+
     pub fn validate_not_paused(&self) -> Result<()> {
         require!(!self.paused, SnitchError::GamePaused);
         Ok(())
@@ -129,17 +369,322 @@ impl GameState {
                 SnitchError::Unauthorized
             );
         } else {
-            self.validate_authority(signer)?;
+            self.validate_role(Role::Selector, signer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Admin role that can be delegated away from the authority
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    /// Pause the game, and resume it while the multisig is off
+    Pauser,
+    /// Update the game config
+    ConfigManager,
+    /// Select players when VRF is off, reset cooldowns
+    Selector,
+    /// Move funds out of the vaults (round cancellation refunds)
+    Treasury,
+}
+
+/// Holders of the delegated admin roles
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Roles {
+    pub pauser: Option<Pubkey>,
+    pub config_manager: Option<Pubkey>,
+    pub selector: Option<Pubkey>,
+    pub treasury: Option<Pubkey>,
+}
+
+impl Roles {
+    pub const SIZE: usize = 4 * 33;
+
+    pub fn holder(&self, role: Role) -> Option<Pubkey> {
+        match role {
+            Role::Pauser => self.pauser,
+            Role::ConfigManager => self.config_manager,
+            Role::Selector => self.selector,
+            Role::Treasury => self.treasury,
         }
+    }
+
+    pub fn set(&mut self, role: Role, holder: Option<Pubkey>) {
+        match role {
+            Role::Pauser => self.pauser = holder,
+            Role::ConfigManager => self.config_manager = holder,
+            Role::Selector => self.selector = holder,
+            Role::Treasury => self.treasury = holder,
+        }
+    }
+}
+
+/// Built-in M-of-N signer set; disabled while `threshold` is 0
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Multisig {
+    /// Members (at most `MAX_MEMBERS`)
+    pub members: Vec<Pubkey>,
+    /// Approvals needed to execute a proposal
+    pub threshold: u8,
+    /// Bumped whenever the members change, invalidating open proposals
+    pub signer_set_seq: u32,
+    /// Number of proposals created (next proposal ID)
+    pub proposal_count: u64,
+}
+
+impl Multisig {
+    pub const MAX_MEMBERS: usize = 10;
+    
+    pub const SIZE: usize = 
+        4 + 32 * Self::MAX_MEMBERS + // members
+        1 +  // threshold
+        4 +  // signer_set_seq
+        8;   // proposal_count
+
+    pub fn validate_signer_set(members: &[Pubkey], threshold: u8) -> Result<()> {
+        require!(
+            !members.is_empty() && members.len() <= Self::MAX_MEMBERS,
+            SnitchError::InvalidMultisig
+        );
+        require!(
+            threshold >= 1 && threshold as usize <= members.len(),
+            SnitchError::InvalidMultisig
+        );
+        for (i, member) in members.iter().enumerate() {
+            require!(!members[..i].contains(member), SnitchError::InvalidMultisig);
+        }
+        Ok(())
+    }
+
+    /// Replace the signer set, invalidating open proposals
+    pub fn set_signers(&mut self, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        Self::validate_signer_set(&members, threshold)?;
+        self.members = members;
+        self.threshold = threshold;
+        self.signer_set_seq = self.signer_set_seq.wrapping_add(1);
+        Ok(())
+    }
+
+    pub fn member_index(&self, key: &Pubkey) -> Result<usize> {
+        require!(self.threshold > 0, SnitchError::InvalidMultisig);
+        self.members
+            .iter()
+            .position(|member| member == key)
+            .ok_or(error!(SnitchError::NotMultisigMember))
+    }
+}
+
+/// Holder governance parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Governance {
+    /// Number of proposals created (next proposal ID)
+    pub proposal_count: u64,
+    /// Voting window length in seconds
+    pub voting_period: i64,
+    /// Minimum locked tokens voting for a proposal for it to pass
+    pub quorum: u64,
+}
+
+impl Governance {
+    pub const SIZE: usize = 8 + 8 + 8;
+    pub const DEFAULT_VOTING_PERIOD: i64 = 3 * 86400; // 3 days
+    /// How long after voting closes a passed proposal may be executed
+    pub const EXECUTION_WINDOW: i64 = 7 * 86400; // 7 days
+    /// Default quorum in multiples of the selection balance threshold (1% of supply by default)
+    pub const DEFAULT_QUORUM_THRESHOLDS: u64 = 10;
+
+    pub fn default_quorum(config: &GameConfig) -> u64 {
+        config
+            .min_balance_threshold
+            .saturating_mul(Self::DEFAULT_QUORUM_THRESHOLDS)
+            .max(1)
+    }
+
+    pub fn validate_params(voting_period: i64, quorum: u64) -> Result<()> {
+        require!(voting_period > 0, SnitchError::InvalidConfig);
+        require!(quorum > 0, SnitchError::InvalidConfig);
+        Ok(())
+    }
+
+    pub fn set(&mut self, voting_period: i64, quorum: u64) -> Result<()> {
+        Self::validate_params(voting_period, quorum)?;
+        self.voting_period = voting_period;
+        self.quorum = quorum;
         Ok(())
     }
 }
 
+/// Holder proposal to replace the game config
+#[account]
+pub struct GovernanceProposal {
+    /// Proposal ID
+    pub id: u64,
+    /// Holder who created the proposal
+    pub proposer: Pubkey,
+    /// Proposed configuration
+    pub config: GameConfig,
+    /// Voting opens at
+    pub voting_starts_at: i64,
+    /// Voting closes at
+    pub voting_ends_at: i64,
+    /// Locked tokens voting for
+    pub votes_for: u64,
+    /// Locked tokens voting against
+    pub votes_against: u64,
+    /// Whether the proposal has been sent to the config-update path
+    pub executed: bool,
+    /// Bump for PDA
+    pub bump: u8,
+    /// Votes whose tokens are still locked in the escrow
+    pub open_votes: u32,
+}
+
+impl GovernanceProposal {
+    pub const SIZE: usize = 
+        8 +  // discriminator
+        8 +  // id
+        32 + // proposer
+        GameConfig::SIZE + // config
+        8 +  // voting_starts_at
+        8 +  // voting_ends_at
+        8 +  // votes_for
+        8 +  // votes_against
+        1 +  // executed
+        1 +  // bump
+        4;   // open_votes
+
+    pub fn is_voting_open(&self, now: i64) -> bool {
+        now >= self.voting_starts_at && now < self.voting_ends_at
+    }
+
+    pub fn has_passed(&self, quorum: u64) -> bool {
+        self.votes_for > self.votes_against && self.votes_for >= quorum
+    }
+
+    /// Whether a passed proposal may no longer be executed
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.voting_ends_at.saturating_add(Governance::EXECUTION_WINDOW)
+    }
+
+    /// Whether voting has closed and the proposal can no longer change the config
+    pub fn is_settled(&self, quorum: u64, now: i64) -> bool {
+        now >= self.voting_ends_at
+            && (self.executed || !self.has_passed(quorum) || self.is_expired(now))
+    }
+}
+
+/// A holder's locked vote on a governance proposal
+#[account]
+pub struct VoteRecord {
+    /// Proposal voted on
+    pub proposal: Pubkey,
+    /// Voter wallet
+    pub voter: Pubkey,
+    /// Tokens locked in the proposal's vote escrow
+    pub amount: u64,
+    /// Whether the vote was for the proposal
+    pub support: bool,
+    /// Bump for PDA
+    pub bump: u8,
+}
+
+impl VoteRecord {
+    pub const SIZE: usize = 
+        8 +  // discriminator
+        32 + // proposal
+        32 + // voter
+        8 +  // amount
+        1 +  // support
+        1;   // bump
+}
+
+/// Admin action executed by the multisig
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub enum MultisigAction {
+    /// Propose a config (still subject to the config timelock)
+    UpdateConfig { config: GameConfig },
+    /// Drop the pending config
+    CancelConfig,
+    Pause,
+    Resume,
+    ResetCooldown { player: Pubkey },
+    /// Replace the signer set
+    SetSigners { members: Vec<Pubkey>, threshold: u8 },
+    /// Raise the config timelock
+    SetConfigTimelock { timelock: i64 },
+    /// Propose a new authority (still accepted by its key)
+    ProposeAuthority { new_authority: Pubkey },
+    /// Assign or revoke (`None`) a delegated role
+    AssignRole { role: Role, holder: Option<Pubkey> },
+    /// Set the governance voting window and quorum
+    SetGovernance { voting_period: i64, quorum: u64 },
+}
+
+impl MultisigAction {
+    const CONFIG_SIZE: usize = GameConfig::SIZE;
+    const SIGNERS_SIZE: usize = 4 + 32 * Multisig::MAX_MEMBERS + 1;
+    
+    /// Variant tag + largest payload
+    pub const SIZE: usize = 1 + if Self::CONFIG_SIZE > Self::SIGNERS_SIZE {
+        Self::CONFIG_SIZE
+    } else {
+        Self::SIGNERS_SIZE
+    };
+}
+
+/// Multisig proposal PDA
+#[account]
+pub struct MultisigProposal {
+    /// Proposal ID
+    pub id: u64,
+    /// Member who created the proposal
+    pub proposer: Pubkey,
+    /// Action to execute
+    pub action: MultisigAction,
+    /// Bitmask of approving member indices
+    pub approvals: u16,
+    /// Signer set the proposal was created under
+    pub signer_set_seq: u32,
+    /// Creation timestamp
+    pub created_at: i64,
+    /// Whether the proposal has been executed
+    pub executed: bool,
+    /// Bump for PDA
+    pub bump: u8,
+}
+
+impl MultisigProposal {
+    pub const SIZE: usize = 
+        8 +  // discriminator
+        8 +  // id
+        32 + // proposer
+        MultisigAction::SIZE + // action
+        2 +  // approvals
+        4 +  // signer_set_seq
+        8 +  // created_at
+        1 +  // executed
+        1;   // bump
+
+    pub fn approval_count(&self) -> u32 {
+        self.approvals.count_ones()
+    }
+
+    /// Record a member's approval; returns false if they had already approved
+    pub fn approve(&mut self, member_index: usize) -> bool {
+        let bit = 1u16 << member_index;
+        let fresh = self.approvals & bit == 0;
+        self.approvals |= bit;
+        fresh
+    }
+}
+
 /// Choice enum for player decisions
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Choice {
     Cooperate = 0,
     Snitch = 1,
+    /// Stay silent: pay a fee to opt out of the dilemma
+    LawyerUp = 2,
 }
 
 impl Choice {
@@ -147,6 +692,7 @@ impl Choice {
         match value {
             0 => Ok(Choice::Cooperate),
             1 => Ok(Choice::Snitch),
+            2 => Ok(Choice::LawyerUp),
             _ => Err(SnitchError::InvalidChoice.into()),
         }
     }
@@ -155,6 +701,90 @@ impl Choice {
         match self {
             Choice::Cooperate => 0,
             Choice::Snitch => 1,
+            Choice::LawyerUp => 2,
+        }
+    }
+}
+
+/// Autopilot strategy applied when a selected player fails to commit
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Strategy {
+    /// No autopilot (missing commits default to COOPERATE)
+    None,
+    AlwaysCooperate,
+    AlwaysSnitch,
+    /// Repeat the opponent's last choice against this player, falling back
+    /// to their last choice overall (COOPERATE if unknown). An opponent who
+    /// lawyered up is answered with COOPERATE, since autopilot pays no fee
+    TitForTat,
+    /// SNITCH with the given probability in basis points. The roll is derived
+    /// from the public selection seed, so anyone (the opponent included) can
+    /// compute it once the players are selected: it is not a secret
+    Random { snitch_bias_bps: u16 },
+}
+
+impl Strategy {
+    pub const SIZE: usize = 1 + 2; // variant + largest payload
+
+    pub fn validate(&self) -> Result<()> {
+        if let Strategy::Random { snitch_bias_bps } = self {
+            require!(*snitch_bias_bps <= 10000, SnitchError::InvalidStrategy);
+        }
+        Ok(())
+    }
+
+    /// Pick a choice on the player's behalf, or None if autopilot is off
+    pub fn decide(
+        &self,
+        opponent_last_choice: Option<Choice>,
+        entropy: [u8; 32],
+    ) -> Option<Choice> {
+        match self {
+            Strategy::None => None,
+            Strategy::AlwaysCooperate => Some(Choice::Cooperate),
+            Strategy::AlwaysSnitch => Some(Choice::Snitch),
+            Strategy::TitForTat => match opponent_last_choice {
+                Some(Choice::Snitch) => Some(Choice::Snitch),
+                _ => Some(Choice::Cooperate),
+            },
+            Strategy::Random { snitch_bias_bps } => {
+                let roll = u16::from_le_bytes([entropy[0], entropy[1]]) % 10000;
+                if roll < *snitch_bias_bps {
+                    Some(Choice::Snitch)
+                } else {
+                    Some(Choice::Cooperate)
+                }
+            }
+        }
+    }
+}
+
+/// Milestone badges, minted as soulbound tokens
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Badge {
+    /// Snitched on a cooperating opponent
+    FirstBetrayal = 0,
+    /// Got snitched on while cooperating
+    SurvivedSnitch = 1,
+    /// Cooperated five times
+    FiveTimeCooperator = 2,
+    /// Snitched on a snitch
+    MutualDestruction = 3,
+}
+
+impl Badge {
+    pub const SYMBOL: &'static str = "SNITCHB";
+
+    pub fn bit(self) -> u8 {
+        1 << (self as u8)
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Badge::FirstBetrayal => "SNITCH: First Betrayal",
+            Badge::SurvivedSnitch => "SNITCH: Survived a Snitch",
+            Badge::FiveTimeCooperator => "SNITCH: 5x Cooperator",
+            Badge::MutualDestruction => "SNITCH: Mutual Destruction",
         }
     }
 }
@@ -168,6 +798,10 @@ pub enum Outcome {
     PlayerBSnitched = 3,
     MutualSnitching = 4,
     Timeout = 5,
+    PlayerALawyeredUp = 6,
+    PlayerBLawyeredUp = 7,
+    MutualLawyerUp = 8,
+    Cancelled = 9,
 }
 
 /// Phase of an interrogation round
@@ -178,7 +812,34 @@ pub enum Phase {
     Resolved = 2,
 }
 
+/// One player's profit and loss for a single round, by source
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RoundPnl {
+    /// Yield or snitch reward credited to pending rewards
+    pub rewards: u64,
+    /// Side pot payout (including cooperation bonus) minus the wager
+    pub side_pot: i64,
+    /// Bribe received minus bribe paid
+    pub bribes: i64,
+    /// Lawyer and commitment revision fees paid
+    pub fees: u64,
+}
+
+impl RoundPnl {
+    pub const SIZE: usize = 8 + 8 + 8 + 8;
+
+    pub fn net(&self) -> i64 {
+        let net = self.rewards as i128 + self.side_pot as i128 + self.bribes as i128
+            - self.fees as i128;
+        net.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+    }
+}
+
 /// Interrogation round PDA
+///
+/// Unversioned: an upgrade that changes this layout must wait until the
+/// active round has been resolved or cancelled, so no open round is decoded
+/// with the new layout. New fields go at the end.
 #[account]
 pub struct Interrogation {
     /// Unique round ID
@@ -215,10 +876,52 @@ pub struct Interrogation {
     pub player_b_reward: u64,
     /// Amount burned in this round
     pub amount_burned: u64,
+    /// Lawyer fee paid by player A at reveal
+    pub lawyer_fee_a: u64,
+    /// Lawyer fee paid by player B at reveal
+    pub lawyer_fee_b: u64,
+    /// Player A's side wager in the round's side pot
+    pub side_pot_a: u64,
+    /// Player B's side wager in the round's side pot
+    pub side_pot_b: u64,
+    /// Bribe escrowed by player A, payable to B if B cooperates
+    pub bribe_a: u64,
+    /// Bribe escrowed by player B, payable to A if A cooperates
+    pub bribe_b: u64,
+    /// Player A's public pledge (made before committing)
+    pub pledge_a: Option<Choice>,
+    /// Player B's public pledge (made before committing)
+    pub pledge_b: Option<Choice>,
+    /// Whether player A's choice was made by autopilot
+    pub autopilot_a: bool,
+    /// Whether player B's choice was made by autopilot
+    pub autopilot_b: bool,
+    /// Number of times player A revised their commitment
+    pub revisions_a: u16,
+    /// Number of times player B revised their commitment
+    pub revisions_b: u16,
+    /// Player who forfeited the round (if any)
+    pub forfeited_by: Option<Pubkey>,
+    /// Player A's profit and loss for this round
+    pub pnl_a: RoundPnl,
+    /// Player B's profit and loss for this round
+    pub pnl_b: RoundPnl,
     /// Whether rewards have been distributed
     pub rewards_distributed: bool,
+    /// Resolution (or cancellation) timestamp
+    pub resolved_at: i64,
+    /// Account that paid the round's rent (refunded on close)
+    pub payer: Pubkey,
     /// Bump for PDA
     pub bump: u8,
+    /// Seed fixed at selection, source of autopilot entropy
+    pub selection_seed: [u8; 32],
+    /// Player A's `last_selected_at` before this round (restored on cancel)
+    pub previous_selected_at_a: i64,
+    /// Player B's `last_selected_at` before this round (restored on cancel)
+    pub previous_selected_at_b: i64,
+    /// Payoff rules in force when the players were selected
+    pub payoff_config: PayoffConfig,
 }
 
 impl Interrogation {
@@ -241,8 +944,36 @@ impl Interrogation {
         8 +   // player_a_reward
         8 +   // player_b_reward
         8 +   // amount_burned
+        8 +   // lawyer_fee_a
+        8 +   // lawyer_fee_b
+        8 +   // side_pot_a
+        8 +   // side_pot_b
+        8 +   // bribe_a
+        8 +   // bribe_b
+        2 +   // pledge_a (Option<Choice>)
+        2 +   // pledge_b (Option<Choice>)
+        1 +   // autopilot_a
+        1 +   // autopilot_b
+        2 +   // revisions_a
+        2 +   // revisions_b
+        33 +  // forfeited_by (Option<Pubkey>)
+        RoundPnl::SIZE + // pnl_a
+        RoundPnl::SIZE + // pnl_b
         1 +   // rewards_distributed
-        1;    // bump
+        8 +   // resolved_at
+        32 +  // payer
+        1 +   // bump
+        32 +  // selection_seed
+        8 +   // previous_selected_at_a
+        8 +   // previous_selected_at_b
+        PayoffConfig::SIZE; // payoff_config
+
+    /// How long a finished round stays on-chain before it can be closed
+    pub const RETENTION_PERIOD: i64 = 30 * 86400;
+
+    pub fn can_close(&self, now: i64) -> bool {
+        self.outcome != Outcome::Pending && now >= self.resolved_at + Self::RETENTION_PERIOD
+    }
 
     pub fn get_current_phase(&self, now: i64) -> Phase {
         if self.outcome != Outcome::Pending {
@@ -271,6 +1002,16 @@ impl Interrogation {
         }
     }
 
+    /// Lower of the two player keys (pair history seed order)
+    pub fn player_lo(&self) -> &Pubkey {
+        PairHistory::lo(&self.player_a, &self.player_b)
+    }
+
+    /// Higher of the two player keys (pair history seed order)
+    pub fn player_hi(&self) -> &Pubkey {
+        PairHistory::hi(&self.player_a, &self.player_b)
+    }
+
     pub fn both_committed(&self) -> bool {
         self.commitment_a.is_some() && self.commitment_b.is_some()
     }
@@ -291,14 +1032,454 @@ impl Interrogation {
         if self.outcome != Outcome::Pending {
             return false;
         }
-        // Can resolve if both revealed or if reveal phase ended
-        self.both_revealed() || now > self.reveal_ends_at
+        // Can resolve if both revealed, a player forfeited, or reveal phase ended
+        self.both_revealed() || self.forfeited_by.is_some() || now > self.reveal_ends_at
+    }
+
+    /// A player may forfeit until they reveal or the reveal window closes
+    pub fn validate_forfeit(&self, player: &Pubkey, now: i64) -> Result<()> {
+        require!(self.outcome == Outcome::Pending, SnitchError::AlreadyResolved);
+        let revealed = match self.get_player_index(player)? {
+            0 => self.revealed_a,
+            _ => self.revealed_b,
+        };
+        require!(!revealed, SnitchError::AlreadyRevealed);
+        require!(now <= self.reveal_ends_at, SnitchError::RevealPhaseEnded);
+        Ok(())
+    }
+}
+
+/// Head-to-head history between two players, seeded by the sorted pair
+#[account]
+pub struct PairHistory {
+    /// Lower of the two player keys
+    pub player_lo: Pubkey,
+    /// Higher of the two player keys
+    pub player_hi: Pubkey,
+    /// Total rounds played against each other
+    pub rounds_played: u64,
+    /// Rounds ending in mutual cooperation
+    pub mutual_cooperations: u64,
+    /// Rounds where only player_lo snitched
+    pub lo_snitched: u64,
+    /// Rounds where only player_hi snitched
+    pub hi_snitched: u64,
+    /// Rounds ending in mutual snitching
+    pub mutual_snitchings: u64,
+    /// Rounds where someone lawyered up and nobody snitched
+    pub lawyered_up: u64,
+    /// Last round played between the pair
+    pub last_round_id: u64,
+    /// player_lo's last choice against player_hi
+    pub last_choice_lo: Option<Choice>,
+    /// player_hi's last choice against player_lo
+    pub last_choice_hi: Option<Choice>,
+    /// Bump for PDA
+    pub bump: u8,
+}
+
+impl PairHistory {
+    pub const SIZE: usize = 
+        8 +   // discriminator
+        32 +  // player_lo
+        32 +  // player_hi
+        8 +   // rounds_played
+        8 +   // mutual_cooperations
+        8 +   // lo_snitched
+        8 +   // hi_snitched
+        8 +   // mutual_snitchings
+        8 +   // lawyered_up
+        8 +   // last_round_id
+        2 +   // last_choice_lo (Option<Choice>)
+        2 +   // last_choice_hi (Option<Choice>)
+        1;    // bump
+
+    /// Lower of two player keys (first seed)
+    pub fn lo<'a>(a: &'a Pubkey, b: &'a Pubkey) -> &'a Pubkey {
+        if a <= b { a } else { b }
+    }
+
+    /// Higher of two player keys (second seed)
+    pub fn hi<'a>(a: &'a Pubkey, b: &'a Pubkey) -> &'a Pubkey {
+        if a <= b { b } else { a }
+    }
+
+    /// Last choice `player` made against the other member of the pair
+    pub fn last_choice_of(&self, player: &Pubkey) -> Option<Choice> {
+        if *player == self.player_lo {
+            self.last_choice_lo
+        } else if *player == self.player_hi {
+            self.last_choice_hi
+        } else {
+            None
+        }
+    }
+
+    pub fn record(
+        &mut self,
+        interrogation: &Interrogation,
+        outcome: Outcome,
+        choice_a: Choice,
+        choice_b: Choice,
+    ) -> Result<()> {
+        let a_is_lo = interrogation.player_a == *interrogation.player_lo();
+        
+        let counter = match outcome {
+            Outcome::MutualCooperation => &mut self.mutual_cooperations,
+            Outcome::MutualSnitching => &mut self.mutual_snitchings,
+            Outcome::PlayerASnitched if a_is_lo => &mut self.lo_snitched,
+            Outcome::PlayerASnitched => &mut self.hi_snitched,
+            Outcome::PlayerBSnitched if a_is_lo => &mut self.hi_snitched,
+            Outcome::PlayerBSnitched => &mut self.lo_snitched,
+            Outcome::PlayerALawyeredUp
+            | Outcome::PlayerBLawyeredUp
+            | Outcome::MutualLawyerUp => &mut self.lawyered_up,
+            _ => return Err(SnitchError::InvalidOutcome.into()),
+        };
+        *counter = counter.checked_add(1).ok_or(SnitchError::Overflow)?;
+        
+        self.rounds_played = self.rounds_played
+            .checked_add(1)
+            .ok_or(SnitchError::Overflow)?;
+        self.last_round_id = interrogation.round_id;
+        
+        if a_is_lo {
+            self.last_choice_lo = Some(choice_a);
+            self.last_choice_hi = Some(choice_b);
+        } else {
+            self.last_choice_lo = Some(choice_b);
+            self.last_choice_hi = Some(choice_a);
+        }
+        Ok(())
+    }
+}
+
+/// A single ranked player on a leaderboard
+#[zero_copy]
+#[derive(Debug, Default)]
+pub struct LeaderboardEntry {
+    /// Player pubkey (default if the slot is empty)
+    pub player: Pubkey,
+    /// Ranking value
+    pub score: i64,
+}
+
+/// Top-N rankings, updated in resolve_round
+#[account(zero_copy)]
+pub struct Leaderboard {
+    /// Game state this leaderboard belongs to
+    pub game_state: Pubkey,
+    /// Last round that updated the leaderboard
+    pub updated_round: u64,
+    /// Top players by net PnL
+    pub by_net_pnl: [LeaderboardEntry; Leaderboard::MAX_ENTRIES],
+    /// Top players by times snitched
+    pub by_snitches: [LeaderboardEntry; Leaderboard::MAX_ENTRIES],
+    /// Top players by current cooperation streak
+    pub by_cooperation_streak: [LeaderboardEntry; Leaderboard::MAX_ENTRIES],
+    /// Bump for PDA
+    pub bump: u8,
+    /// Padding for alignment
+    pub padding: [u8; 7],
+}
+
+impl Leaderboard {
+    pub const MAX_ENTRIES: usize = 10;
+    
+    pub const SIZE: usize = 
+        8 +   // discriminator
+        32 +  // game_state
+        8 +   // updated_round
+        3 * Self::MAX_ENTRIES * (32 + 8) + // boards
+        1 +   // bump
+        7;    // padding
+
+    /// Record a player's latest score on each board
+    pub fn record(&mut self, profile: &PlayerProfile, round_id: u64) {
+        Self::upsert(&mut self.by_net_pnl, profile.player, profile.net_pnl());
+        Self::upsert(&mut self.by_snitches, profile.player, profile.times_snitched as i64);
+        Self::upsert(
+            &mut self.by_cooperation_streak,
+            profile.player,
+            profile.cooperation_streak as i64,
+        );
+        self.updated_round = round_id;
+    }
+
+    /// Insert or move `player` keeping the board sorted by descending score
+    fn upsert(board: &mut [LeaderboardEntry; Self::MAX_ENTRIES], player: Pubkey, score: i64) {
+        let len = board.len();
+        
+        // Drop the player's existing entry, shifting the tail up
+        if let Some(pos) = board.iter().position(|entry| entry.player == player) {
+            board.copy_within(pos + 1.., pos);
+            board[len - 1] = LeaderboardEntry::default();
+        }
+        
+        let is_empty = |entry: &LeaderboardEntry| entry.player == Pubkey::default();
+        let Some(pos) = board
+            .iter()
+            .position(|entry| is_empty(entry) || score > entry.score)
+        else {
+            return;
+        };
+        
+        board.copy_within(pos..len - 1, pos + 1);
+        board[pos] = LeaderboardEntry { player, score };
+    }
+}
+
+/// Compact record of a resolved round
+#[zero_copy]
+#[derive(Debug, Default)]
+pub struct RoundRecord {
+    /// Round ID (0 if the slot is empty)
+    pub round_id: u64,
+    /// Resolution timestamp
+    pub resolved_at: i64,
+    /// Player A pubkey
+    pub player_a: Pubkey,
+    /// Player B pubkey
+    pub player_b: Pubkey,
+    /// Reward credited to player A
+    pub reward_a: u64,
+    /// Reward credited to player B
+    pub reward_b: u64,
+    /// Total side pot wagered
+    pub side_pot: u64,
+    /// Amount burned in the round
+    pub amount_burned: u64,
+    /// Player A's choice (`Choice as u8`, `NO_CHOICE` if none)
+    pub choice_a: u8,
+    /// Player B's choice (`Choice as u8`, `NO_CHOICE` if none)
+    pub choice_b: u8,
+    /// Outcome (`Outcome as u8`)
+    pub outcome: u8,
+    /// 0 if nobody forfeited, 1 if player A did, 2 if player B did
+    pub forfeited: u8,
+    /// Padding for alignment
+    pub padding: [u8; 4],
+}
+
+impl RoundRecord {
+    pub const SIZE: usize = 8 + 8 + 32 + 32 + 8 + 8 + 8 + 8 + 1 + 1 + 1 + 1 + 4;
+    pub const NO_CHOICE: u8 = u8::MAX;
+
+    pub fn new(interrogation: &Interrogation) -> Self {
+        let encode = |choice: Option<Choice>| choice.map_or(Self::NO_CHOICE, |c| c as u8);
+        let forfeited = match interrogation.forfeited_by {
+            Some(player) if player == interrogation.player_a => 1,
+            Some(_) => 2,
+            None => 0,
+        };
+        
+        Self {
+            round_id: interrogation.round_id,
+            resolved_at: interrogation.resolved_at,
+            player_a: interrogation.player_a,
+            player_b: interrogation.player_b,
+            reward_a: interrogation.player_a_reward,
+            reward_b: interrogation.player_b_reward,
+            side_pot: interrogation.side_pot_a.saturating_add(interrogation.side_pot_b),
+            amount_burned: interrogation.amount_burned,
+            choice_a: encode(interrogation.choice_a),
+            choice_b: encode(interrogation.choice_b),
+            outcome: interrogation.outcome as u8,
+            forfeited,
+            padding: [0; 4],
+        }
+    }
+}
+
+/// Ring buffer of the most recent resolved rounds, written in resolve_round
+#[account(zero_copy)]
+pub struct RoundHistory {
+    /// Game state this history belongs to
+    pub game_state: Pubkey,
+    /// Total rounds ever recorded (next slot is `total_recorded % MAX_RECORDS`)
+    pub total_recorded: u64,
+    /// Records, oldest overwritten first
+    pub records: [RoundRecord; RoundHistory::MAX_RECORDS],
+    /// Bump for PDA
+    pub bump: u8,
+    /// Padding for alignment
+    pub padding: [u8; 7],
+}
+
+impl RoundHistory {
+    pub const MAX_RECORDS: usize = 64;
+    
+    pub const SIZE: usize = 
+        8 +   // discriminator
+        32 +  // game_state
+        8 +   // total_recorded
+        Self::MAX_RECORDS * RoundRecord::SIZE + // records
+        1 +   // bump
+        7;    // padding
+
+    /// Overwrite the oldest slot with `record`
+    pub fn push(&mut self, record: RoundRecord) {
+        let slot = (self.total_recorded % Self::MAX_RECORDS as u64) as usize;
+        self.records[slot] = record;
+        self.total_recorded = self.total_recorded.saturating_add(1);
+    }
+
+    /// Records from newest to oldest
+    pub fn recent(&self) -> impl Iterator<Item = &RoundRecord> {
+        let len = self.total_recorded.min(Self::MAX_RECORDS as u64) as usize;
+        let newest = self.total_recorded as usize;
+        (1..=len).map(move |back| &self.records[(newest - back) % Self::MAX_RECORDS])
+    }
+}
+
+/// A single message in the interrogation room
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct Message {
+    /// Player who posted the message
+    pub sender: Pubkey,
+    /// Post timestamp
+    pub posted_at: i64,
+    /// Message text (max MessageLog::MAX_MESSAGE_LEN bytes)
+    pub text: String,
+}
+
+impl Message {
+    pub const SIZE: usize = 
+        32 +  // sender
+        8 +   // posted_at
+        4 + MessageLog::MAX_MESSAGE_LEN; // text
+}
+
+/// Append-only suspect chat for an interrogation round
+#[account]
+pub struct MessageLog {
+    /// Interrogation this log belongs to
+    pub interrogation: Pubkey,
+    /// Round ID
+    pub round_id: u64,
+    /// First poster, who paid the log's rent (refunded on close)
+    pub payer: Pubkey,
+    /// Messages in posting order
+    pub messages: Vec<Message>,
+    /// Bump for PDA
+    pub bump: u8,
+}
+
+impl MessageLog {
+    pub const MAX_MESSAGES: usize = 16;
+    pub const MAX_MESSAGE_LEN: usize = 280;
+    
+    pub const SIZE: usize = 
+        8 +   // discriminator
+        32 +  // interrogation
+        8 +   // round_id
+        32 +  // payer
+        4 + Self::MAX_MESSAGES * Message::SIZE + // messages
+        1;    // bump
+}
+
+/// Lifetime record of a closed profile, kept under its own PDA so the player
+/// can't come back with a clean record. Restored into the profile when it is
+/// re-created, then closed with its rent returned to the player.
+#[account]
+pub struct ProfileTombstone {
+    /// Player whose profile was closed
+    pub player: Pubkey,
+    /// When the profile was closed
+    pub closed_at: i64,
+    pub times_selected: u64,
+    pub times_cooperated: u64,
+    pub times_snitched: u64,
+    pub times_lawyered_up: u64,
+    pub promises_broken: u64,
+    pub total_rewards_earned: u64,
+    pub total_penalties_paid: u64,
+    pub last_selected_at: i64,
+    pub trust_score: u16,
+    pub trust_updated_day: u32,
+    pub badges_earned: u8,
+    pub badges_minted: u8,
+    pub best_cooperation_streak: u32,
+    pub best_snitch_streak: u32,
+    pub best_survival_streak: u32,
+    pub pnl_gains: u64,
+    pub pnl_losses: u64,
+}
+
+impl ProfileTombstone {
+    pub const SIZE: usize = 
+        8 +  // discriminator
+        32 + // player
+        8 +  // closed_at
+        8 +  // times_selected
+        8 +  // times_cooperated
+        8 +  // times_snitched
+        8 +  // times_lawyered_up
+        8 +  // promises_broken
+        8 +  // total_rewards_earned
+        8 +  // total_penalties_paid
+        8 +  // last_selected_at
+        2 +  // trust_score
+        4 +  // trust_updated_day
+        1 +  // badges_earned
+        1 +  // badges_minted
+        4 +  // best_cooperation_streak
+        4 +  // best_snitch_streak
+        4 +  // best_survival_streak
+        8 +  // pnl_gains
+        8;   // pnl_losses
+
+    pub fn new(profile: &PlayerProfile, closed_at: i64) -> Self {
+        Self {
+            player: profile.player,
+            closed_at,
+            times_selected: profile.times_selected,
+            times_cooperated: profile.times_cooperated,
+            times_snitched: profile.times_snitched,
+            times_lawyered_up: profile.times_lawyered_up,
+            promises_broken: profile.promises_broken,
+            total_rewards_earned: profile.total_rewards_earned,
+            total_penalties_paid: profile.total_penalties_paid,
+            last_selected_at: profile.last_selected_at,
+            trust_score: profile.trust_score,
+            trust_updated_day: profile.trust_updated_day,
+            badges_earned: profile.badges_earned,
+            badges_minted: profile.badges_minted,
+            best_cooperation_streak: profile.best_cooperation_streak,
+            best_snitch_streak: profile.best_snitch_streak,
+            best_survival_streak: profile.best_survival_streak,
+            pnl_gains: profile.pnl_gains,
+            pnl_losses: profile.pnl_losses,
+        }
+    }
+
+    /// Carry the record into a re-created profile
+    pub fn restore(&self, profile: &mut PlayerProfile) {
+        profile.times_selected = self.times_selected;
+        profile.times_cooperated = self.times_cooperated;
+        profile.times_snitched = self.times_snitched;
+        profile.times_lawyered_up = self.times_lawyered_up;
+        profile.promises_broken = self.promises_broken;
+        profile.total_rewards_earned = self.total_rewards_earned;
+        profile.total_penalties_paid = self.total_penalties_paid;
+        profile.last_selected_at = self.last_selected_at;
+        profile.trust_score = self.trust_score;
+        profile.trust_updated_day = self.trust_updated_day;
+        profile.badges_earned = self.badges_earned;
+        profile.badges_minted = self.badges_minted;
+        profile.best_cooperation_streak = self.best_cooperation_streak;
+        profile.best_snitch_streak = self.best_snitch_streak;
+        profile.best_survival_streak = self.best_survival_streak;
+        profile.pnl_gains = self.pnl_gains;
+        profile.pnl_losses = self.pnl_losses;
     }
 }
 
 /// Player profile PDA
 #[account]
 pub struct PlayerProfile {
+    /// Layout version (first field so it sits at a fixed offset)
+    pub version: u8,
     /// Player's wallet pubkey
     pub player: Pubkey,
     /// Total times selected for interrogation
@@ -307,6 +1488,10 @@ pub struct PlayerProfile {
     pub times_cooperated: u64,
     /// Total times snitched
     pub times_snitched: u64,
+    /// Total times lawyered up
+    pub times_lawyered_up: u64,
+    /// Total times the revealed choice contradicted a public pledge
+    pub promises_broken: u64,
     /// Total rewards earned
     pub total_rewards_earned: u64,
     /// Total penalties paid
@@ -321,14 +1506,85 @@ pub struct PlayerProfile {
     pub on_cooldown: bool,
     /// Current round if selected (0 if not)
     pub current_round: u64,
+    /// Session key allowed to commit/reveal on the player's behalf
+    pub delegate: Option<Pubkey>,
+    /// Delegate expires at
+    pub delegate_expires_at: i64,
+    /// Autopilot strategy used when the player fails to commit
+    pub strategy: Strategy,
+    /// Last choice played (revealed or autopilot)
+    pub last_choice: Option<Choice>,
     /// Bump for PDA
     pub bump: u8,
+    /// Trust score in basis points (0 = pure snitch, 10000 = pure cooperator)
+    pub trust_score: u16,
+    /// Day (unix days) the trust score was last updated, 0 if never
+    pub trust_updated_day: u32,
+    /// Bitmask of badges earned (see `Badge::bit`)
+    pub badges_earned: u8,
+    /// Bitmask of badges already minted
+    pub badges_minted: u8,
+    /// Consecutive rounds played as COOPERATE
+    pub cooperation_streak: u32,
+    /// Longest cooperation streak
+    pub best_cooperation_streak: u32,
+    /// Consecutive rounds played as SNITCH
+    pub snitch_streak: u32,
+    /// Longest snitch streak
+    pub best_snitch_streak: u32,
+    /// Consecutive rounds finished without a penalty
+    pub survival_streak: u32,
+    /// Longest survival streak
+    pub best_survival_streak: u32,
+    /// Tokens gained across all rounds (rewards credited, pots and bribes won)
+    pub pnl_gains: u64,
+    /// Tokens lost across all rounds (fees paid, pots and bribes lost)
+    pub pnl_losses: u64,
     /// Reserved for future
-    pub reserved: [u8; 16],
+    pub reserved: [u8; 2],
+    /// Account that paid the profile's rent (refunded on close)
+    pub rent_payer: Pubkey,
 }
 
 impl PlayerProfile {
     pub const SIZE: usize = 
+        8 +  // discriminator
+        1 +  // version
+        32 + // player
+        8 +  // times_selected
+        8 +  // times_cooperated
+        8 +  // times_snitched
+        8 +  // times_lawyered_up
+        8 +  // promises_broken
+        8 +  // total_rewards_earned
+        8 +  // total_penalties_paid
+        8 +  // pending_rewards
+        8 +  // last_selected_at
+        8 +  // cooldown_ends_at
+        1 +  // on_cooldown
+        8 +  // current_round
+        33 + // delegate (Option<Pubkey>)
+        8 +  // delegate_expires_at
+        Strategy::SIZE + // strategy
+        2 +  // last_choice (Option<Choice>)
+        1 +  // bump
+        2 +  // trust_score
+        4 +  // trust_updated_day
+        1 +  // badges_earned
+        1 +  // badges_minted
+        4 +  // cooperation_streak
+        4 +  // best_cooperation_streak
+        4 +  // snitch_streak
+        4 +  // best_snitch_streak
+        4 +  // survival_streak
+        4 +  // best_survival_streak
+        8 +  // pnl_gains
+        8 +  // pnl_losses
+        2 +  // reserved
+        32;  // rent_payer
+
+    /// Size of the pre-versioning layout, the only one without a version byte
+    pub const LEGACY_SIZE: usize = 
         8 +  // discriminator
         32 + // player
         8 +  // times_selected
@@ -344,10 +1600,230 @@ impl PlayerProfile {
         1 +  // bump
         16;  // reserved
 
+    /// Current layout version (see `GameState::CURRENT_VERSION`)
+    pub const CURRENT_VERSION: u8 = 2;
+
+    /// Fill defaults for fields added since the account's version.
+    /// A freshly created profile is version 0. Returns false if already current.
+    pub fn migrate(&mut self) -> bool {
+        if self.version >= Self::CURRENT_VERSION {
+            return false;
+        }
+        // v1: version byte introduced, new stats start empty (see `read_legacy`)
+        // v2: rent_payer, unknown for older profiles so rent goes to the player
+        if self.rent_payer == Pubkey::default() {
+            self.rent_payer = self.player;
+        }
+        self.version = Self::CURRENT_VERSION;
+        true
+    }
+
+    /// Decode a profile written by any layout version (see `GameState::try_deserialize_any_version`)
+    pub fn try_deserialize_any_version(data: &[u8]) -> Result<Self> {
+        let buf = &mut strip_discriminator::<Self>(data)?;
+        if data.len() == Self::LEGACY_SIZE {
+            return Self::read_legacy(buf);
+        }
+        let version: u8 = read_field(buf)?;
+        Ok(Self {
+            version,
+            player: read_field(buf)?,
+            times_selected: read_field(buf)?,
+            times_cooperated: read_field(buf)?,
+            times_snitched: read_field(buf)?,
+            times_lawyered_up: read_field(buf)?,
+            promises_broken: read_field(buf)?,
+            total_rewards_earned: read_field(buf)?,
+            total_penalties_paid: read_field(buf)?,
+            pending_rewards: read_field(buf)?,
+            last_selected_at: read_field(buf)?,
+            cooldown_ends_at: read_field(buf)?,
+            on_cooldown: read_field(buf)?,
+            current_round: read_field(buf)?,
+            delegate: read_field(buf)?,
+            delegate_expires_at: read_field(buf)?,
+            strategy: read_field(buf)?,
+            last_choice: read_field(buf)?,
+            bump: read_field(buf)?,
+            trust_score: read_field(buf)?,
+            trust_updated_day: read_field(buf)?,
+            badges_earned: read_field(buf)?,
+            badges_minted: read_field(buf)?,
+            cooperation_streak: read_field(buf)?,
+            best_cooperation_streak: read_field(buf)?,
+            snitch_streak: read_field(buf)?,
+            best_snitch_streak: read_field(buf)?,
+            survival_streak: read_field(buf)?,
+            best_survival_streak: read_field(buf)?,
+            pnl_gains: read_field(buf)?,
+            pnl_losses: read_field(buf)?,
+            reserved: read_field(buf)?,
+            rent_payer: read_since(buf, version, 2)?,
+        })
+    }
+
+    /// Decode the pre-versioning layout; stats it did not track start empty
+    fn read_legacy(buf: &mut &[u8]) -> Result<Self> {
+        Ok(Self {
+            version: 0,
+            player: read_field(buf)?,
+            times_selected: read_field(buf)?,
+            times_cooperated: read_field(buf)?,
+            times_snitched: read_field(buf)?,
+            times_lawyered_up: 0,
+            promises_broken: 0,
+            total_rewards_earned: read_field(buf)?,
+            total_penalties_paid: read_field(buf)?,
+            pending_rewards: read_field(buf)?,
+            last_selected_at: read_field(buf)?,
+            cooldown_ends_at: read_field(buf)?,
+            on_cooldown: read_field(buf)?,
+            current_round: read_field(buf)?,
+            delegate: None,
+            delegate_expires_at: 0,
+            strategy: Strategy::None,
+            last_choice: None,
+            bump: read_field(buf)?,
+            trust_score: 0,
+            trust_updated_day: 0,
+            badges_earned: 0,
+            badges_minted: 0,
+            cooperation_streak: 0,
+            best_cooperation_streak: 0,
+            snitch_streak: 0,
+            best_snitch_streak: 0,
+            survival_streak: 0,
+            best_survival_streak: 0,
+            pnl_gains: 0,
+            pnl_losses: 0,
+            reserved: [0; 2],
+            rent_payer: Pubkey::default(),
+        })
+    }
+
+    /// Score players start at and decay back towards
+    pub const TRUST_NEUTRAL: u16 = 5000;
+    /// Days for the distance from neutral to halve
+    pub const TRUST_HALF_LIFE_DAYS: u32 = 30;
+    /// Weight of a round in the trust score
+    pub const TRUST_WEIGHT_BPS: u64 = 2000;
+    /// Weight of betraying a cooperator in the trust score
+    pub const TRUST_BETRAYAL_WEIGHT_BPS: u64 = 4000;
+
+    /// Trust score with time decay towards neutral applied
+    pub fn current_trust_score(&self, now: i64) -> u16 {
+        if self.trust_updated_day == 0 {
+            return Self::TRUST_NEUTRAL;
+        }
+        let today = (now / 86400) as u32;
+        let half_lives = today.saturating_sub(self.trust_updated_day) / Self::TRUST_HALF_LIFE_DAYS;
+        if half_lives >= 16 {
+            return Self::TRUST_NEUTRAL;
+        }
+        
+        let neutral = Self::TRUST_NEUTRAL as i32;
+        let distance = (self.trust_score as i32 - neutral) >> half_lives;
+        (neutral + distance) as u16
+    }
+
+    /// Fold a resolved round into the trust score
+    pub fn update_trust_score(&mut self, own: Choice, opponent: Choice, now: i64) {
+        let (sample, weight): (u64, u64) = match (own, opponent) {
+            (Choice::Cooperate, _) => (10000, Self::TRUST_WEIGHT_BPS),
+            (Choice::LawyerUp, _) => (Self::TRUST_NEUTRAL as u64, Self::TRUST_WEIGHT_BPS),
+            (Choice::Snitch, Choice::Cooperate) => (0, Self::TRUST_BETRAYAL_WEIGHT_BPS),
+            (Choice::Snitch, _) => (0, Self::TRUST_WEIGHT_BPS),
+        };
+        
+        let score = self.current_trust_score(now) as u64;
+        let updated = (score * (10000 - weight) + sample * weight) / 10000;
+        
+        self.trust_score = updated as u16;
+        self.trust_updated_day = ((now / 86400) as u32).max(1);
+    }
+
+    /// Net profit and loss from actual token movements
+    ///
+    /// Rewards count once credited, so the wallet delta is this minus `pending_rewards`.
+    pub fn net_pnl(&self) -> i64 {
+        let net = self.pnl_gains as i128 - self.pnl_losses as i128;
+        net.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+    }
+
+    /// Book a signed token movement into the profile's PnL
+    pub fn book_pnl(&mut self, amount: i64) -> Result<()> {
+        if amount >= 0 {
+            self.pnl_gains = self.pnl_gains
+                .checked_add(amount.unsigned_abs())
+                .ok_or(SnitchError::Overflow)?;
+        } else {
+            self.pnl_losses = self.pnl_losses
+                .checked_add(amount.unsigned_abs())
+                .ok_or(SnitchError::Overflow)?;
+        }
+        Ok(())
+    }
+
+    /// Reverse a loss that was refunded (e.g. a cancelled round's lawyer fee)
+    pub fn refund_loss(&mut self, amount: u64) {
+        self.pnl_losses = self.pnl_losses.saturating_sub(amount);
+    }
+
+    /// Advance or reset streaks after a resolved round
+    pub fn record_streaks(&mut self, choice: Choice, penalty: u64) {
+        fn advance(streak: &mut u32, best: &mut u32, hit: bool) {
+            *streak = if hit { streak.saturating_add(1) } else { 0 };
+            *best = (*best).max(*streak);
+        }
+        
+        advance(
+            &mut self.cooperation_streak,
+            &mut self.best_cooperation_streak,
+            choice == Choice::Cooperate,
+        );
+        advance(
+            &mut self.snitch_streak,
+            &mut self.best_snitch_streak,
+            choice == Choice::Snitch,
+        );
+        advance(
+            &mut self.survival_streak,
+            &mut self.best_survival_streak,
+            penalty == 0,
+        );
+    }
+
+    pub fn award_badge(&mut self, badge: Badge) {
+        self.badges_earned |= badge.bit();
+    }
+
+    pub fn has_earned_badge(&self, badge: Badge) -> bool {
+        self.badges_earned & badge.bit() != 0
+    }
+
+    pub fn has_minted_badge(&self, badge: Badge) -> bool {
+        self.badges_minted & badge.bit() != 0
+    }
+
+    pub fn mark_badge_minted(&mut self, badge: Badge) {
+        self.badges_minted |= badge.bit();
+    }
+
     pub fn is_on_cooldown(&self, now: i64) -> bool {
         self.on_cooldown && now < self.cooldown_ends_at
     }
 
+    pub fn validate_player_or_delegate(&self, signer: &Pubkey, now: i64) -> Result<()> {
+        if self.player == *signer {
+            return Ok(());
+        }
+        require!(
+            self.delegate == Some(*signer) && now < self.delegate_expires_at,
+            SnitchError::Unauthorized
+        );
+        Ok(())
+    }
+
     pub fn set_cooldown(&mut self, duration: i64, now: i64) {
         self.on_cooldown = true;
         self.cooldown_ends_at = now + duration;
@@ -376,6 +1852,41 @@ impl PlayerProfile {
         Ok(())
     }
 
+    fn choice_counter(&mut self, choice: Choice) -> &mut u64 {
+        match choice {
+            Choice::Cooperate => &mut self.times_cooperated,
+            Choice::Snitch => &mut self.times_snitched,
+            Choice::LawyerUp => &mut self.times_lawyered_up,
+        }
+    }
+
+    /// Count a choice played in a round, whether revealed or made by autopilot
+    pub fn record_choice(&mut self, choice: Choice) -> Result<()> {
+        let counter = self.choice_counter(choice);
+        *counter = counter
+            .checked_add(1)
+            .ok_or(SnitchError::Overflow)?;
+        Ok(())
+    }
+
+    /// Take back a choice counted in a round that was cancelled
+    pub fn unrecord_choice(&mut self, choice: Choice) {
+        let counter = self.choice_counter(choice);
+        *counter = counter.saturating_sub(1);
+    }
+
+    /// Take up to `amount` out of unclaimed rewards, returning what was taken
+    pub fn deduct_pending_rewards(&mut self, amount: u64) -> u64 {
+        let taken = amount.min(self.pending_rewards);
+        self.pending_rewards -= taken;
+        taken
+    }
+
+    /// Take back penalties that were refunded
+    pub fn refund_penalty(&mut self, amount: u64) {
+        self.total_penalties_paid = self.total_penalties_paid.saturating_sub(amount);
+    }
+
     pub fn claim_pending_rewards(&mut self) -> u64 {
         let amount = self.pending_rewards;
         self.pending_rewards = 0;
@@ -407,6 +1918,26 @@ impl YieldVault {
 
 impl GameConfig {
     pub const SIZE: usize = 
+        8 +   // min_balance_threshold
+        8 +   // cooldown_period
+        8 +   // commit_duration
+        8 +   // reveal_duration
+        2 +   // cooperation_yield_bps
+        2 +   // snitch_reward_bps
+        2 +   // double_snitch_penalty_bps
+        2 +   // transfer_tax_bps
+        2 +   // bps_denominator
+        1 +   // use_vrf
+        33 +  // vrf_oracle (Option<Pubkey>)
+        2 +   // lawyer_fee_bps
+        2 +   // lawyer_protection_bps
+        8 +   // commitment_revision_fee
+        2 +   // forfeit_penalty_bps
+        2 +   // streak_bonus_bps
+        2;    // max_streak_bonus_bps
+
+    /// Size of the pre-versioning config, which ended at `vrf_oracle`
+    pub const LEGACY_SIZE: usize = 
         8 +   // min_balance_threshold
         8 +   // cooldown_period
         8 +   // commit_duration
@@ -418,6 +1949,51 @@ impl GameConfig {
         2 +   // bps_denominator
         1 +   // use_vrf
         33;   // vrf_oracle (Option<Pubkey>)
+
+    /// Decode the pre-versioning config; later fields get the `default_with_mint` values
+    fn read_legacy(buf: &mut &[u8]) -> Result<Self> {
+        Ok(Self {
+            min_balance_threshold: read_field(buf)?,
+            cooldown_period: read_field(buf)?,
+            commit_duration: read_field(buf)?,
+            reveal_duration: read_field(buf)?,
+            cooperation_yield_bps: read_field(buf)?,
+            snitch_reward_bps: read_field(buf)?,
+            double_snitch_penalty_bps: read_field(buf)?,
+            transfer_tax_bps: read_field(buf)?,
+            bps_denominator: read_field(buf)?,
+            use_vrf: read_field(buf)?,
+            vrf_oracle: read_field(buf)?,
+            lawyer_fee_bps: 200,          // 2%
+            lawyer_protection_bps: 5000,  // 50%
+            commitment_revision_fee: 0,
+            forfeit_penalty_bps: 10000,   // 100%
+            streak_bonus_bps: 0,
+            max_streak_bonus_bps: 0,
+        })
+    }
+}
+
+/// Check and skip the account discriminator
+fn strip_discriminator<T: Discriminator>(data: &[u8]) -> Result<&[u8]> {
+    require!(data.len() >= 8, ErrorCode::AccountDiscriminatorNotFound);
+    require!(data[..8] == T::DISCRIMINATOR, ErrorCode::AccountDiscriminatorMismatch);
+    Ok(&data[8..])
+}
+
+/// Read the next Borsh field of a raw account
+fn read_field<T: AnchorDeserialize>(buf: &mut &[u8]) -> Result<T> {
+    T::deserialize(buf).map_err(|_| error!(ErrorCode::AccountDidNotDeserialize))
+}
+
+/// Read a field added in layout `since`, or its default if the account predates it.
+/// Older accounts may hold stale bytes past their data, so nothing is read for them.
+fn read_since<T: AnchorDeserialize + Default>(buf: &mut &[u8], version: u8, since: u8) -> Result<T> {
+    if version < since {
+        return Ok(T::default());
+    }
+    read_field(buf)
 }
 
+use anchor_lang::Discriminator;
 use crate::SnitchError;
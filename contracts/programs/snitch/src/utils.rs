@@ -33,8 +33,34 @@ pub fn calculate_yield_reward(
     Ok(reward)
 }
 
+/// Calculate the fee a player pays to the yield pool for lawyering up
+pub fn calculate_lawyer_fee(
+    balance: u64,
+    config: &PayoffConfig,
+) -> Result<u64> {
+    let fee = (balance as u128)
+        .checked_mul(config.lawyer_fee_bps as u128)
+        .ok_or(SnitchError::Overflow)?
+        .checked_div(config.bps_denominator as u128)
+        .ok_or(SnitchError::Underflow)? as u64;
+    
+    Ok(fee)
+}
+
 use crate::SnitchError;
 
+/// Convert a token amount into a signed PnL movement
+pub fn signed_amount(amount: u64) -> Result<i64> {
+    Ok(i64::try_from(amount).map_err(|_| SnitchError::Overflow)?)
+}
+
+/// Convert a token amount paid out into a negative PnL movement
+pub fn signed_loss(amount: u64) -> Result<i64> {
+    Ok(signed_amount(amount)?
+        .checked_neg()
+        .ok_or(SnitchError::Overflow)?)
+}
+
 /// VRF verification placeholder
 /// In production, integrate with Switchboard or Chainlink VRF
 pub fn verify_vrf_proof(
@@ -85,6 +111,36 @@ pub fn select_random_players(
     Ok((eligible_players[index_a], eligible_players[index_b]))
 }
 
+/// Derive a round's selection seed, fixed before either player can commit
+pub fn selection_seed(
+    random_seed: &[u8; 32],
+    slot: u64,
+) -> [u8; 32] {
+    use solana_program::hash::hashv;
+    
+    hashv(&[
+        random_seed,
+        &slot.to_le_bytes(),
+    ]).to_bytes()
+}
+
+/// Derive per-player entropy for autopilot strategies from the round's selection seed,
+/// so the resolver cannot pick a favourable slot to resolve in. The seed is public,
+/// so the result is predictable by anyone from selection onwards
+pub fn autopilot_entropy(
+    interrogation: &Pubkey,
+    player: &Pubkey,
+    selection_seed: &[u8; 32],
+) -> [u8; 32] {
+    use solana_program::hash::hashv;
+    
+    hashv(&[
+        interrogation.as_ref(),
+        player.as_ref(),
+        selection_seed,
+    ]).to_bytes()
+}
+
 /// Generate commitment hash for commit-reveal
 pub fn generate_commitment(
     player: &Pubkey,
@@ -11,6 +11,8 @@ import {
   createMint,
   getOrCreateAssociatedTokenAccount,
   mintTo,
+  getAssociatedTokenAddressSync,
+  getAccount,
   TOKEN_PROGRAM_ID,
   ASSOCIATED_TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
@@ -34,10 +36,62 @@ describe("$SNITCH Contract", () => {
   
   let playerATokenAccount: PublicKey;
   let playerBTokenAccount: PublicKey;
+  // Second member of the 2-of-2 multisig (enabled in Admin Functions)
+  let cosigner: Keypair;
   
   const MINT_DECIMALS = 6;
   const INITIAL_SUPPLY = 1_000_000_000 * 10 ** MINT_DECIMALS; // 1B tokens
 
+  // Where a closed profile's record is kept
+  const tombstoneOf = (player: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("profile_tombstone"), player.toBuffer()],
+      program.programId
+    )[0];
+
+  // Create, co-sign and execute a multisig proposal
+  const runMultisigAction = async (action: any) => {
+    const gameState = await program.account.gameState.fetch(gameStatePDA);
+    const [proposalPDA] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("multisig_proposal"),
+        gameStatePDA.toBuffer(),
+        gameState.multisig.proposalCount.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    await program.methods
+      .createMultisigProposal(action)
+      .accounts({
+        member: authority.publicKey,
+        gameState: gameStatePDA,
+        proposal: proposalPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+    await program.methods
+      .approveMultisigProposal()
+      .accounts({
+        member: cosigner.publicKey,
+        gameState: gameStatePDA,
+        proposal: proposalPDA,
+      })
+      .signers([cosigner])
+      .rpc();
+    await program.methods
+      .executeMultisigProposal()
+      .accounts({
+        executor: authority.publicKey,
+        gameState: gameStatePDA,
+        proposal: proposalPDA,
+        proposer: authority.publicKey,
+        playerProfile: null,
+      })
+      .signers([authority])
+      .rpc();
+  };
+
   before(async () => {
     // Create keypairs
     authority = Keypair.generate();
@@ -120,6 +174,12 @@ describe("$SNITCH Contract", () => {
         bpsDenominator: 10000,
         useVrf: false,
         vrfOracle: null,
+        lawyerFeeBps: 200, // 2%
+        lawyerProtectionBps: 5000, // 50%
+        commitmentRevisionFee: new anchor.BN(0),
+        forfeitPenaltyBps: 10000,
+        streakBonusBps: 0,
+        maxStreakBonusBps: 0,
       };
 
       await program.methods
@@ -143,6 +203,56 @@ describe("$SNITCH Contract", () => {
       assert.equal(gameState.currentRound.toNumber(), 0);
       assert.equal(gameState.paused, false);
     });
+
+    it("Should initialize the leaderboard", async () => {
+      const [leaderboardPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("leaderboard"), gameStatePDA.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializeLeaderboard()
+        .accounts({
+          admin: authority.publicKey,
+          gameState: gameStatePDA,
+          leaderboard: leaderboardPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+
+      const leaderboard = await program.account.leaderboard.fetch(leaderboardPDA);
+      assert.equal(leaderboard.gameState.toBase58(), gameStatePDA.toBase58());
+      assert.equal(leaderboard.byNetPnl.length, 10);
+
+      const gameState = await program.account.gameState.fetch(gameStatePDA);
+      assert.equal(gameState.leaderboard.toBase58(), leaderboardPDA.toBase58());
+    });
+
+    it("Should initialize the round history", async () => {
+      const [roundHistoryPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("round_history"), gameStatePDA.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .initializeRoundHistory()
+        .accounts({
+          admin: authority.publicKey,
+          gameState: gameStatePDA,
+          roundHistory: roundHistoryPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+
+      const roundHistory = await program.account.roundHistory.fetch(roundHistoryPDA);
+      assert.equal(roundHistory.totalRecorded.toNumber(), 0);
+      assert.equal(roundHistory.records.length, 64);
+
+      const gameState = await program.account.gameState.fetch(gameStatePDA);
+      assert.equal(gameState.roundHistory.toBase58(), roundHistoryPDA.toBase58());
+    });
   });
 
   describe("Player Selection", () => {
@@ -169,7 +279,26 @@ describe("$SNITCH Contract", () => {
         program.programId
       );
 
-      await program.methods
+      const [sidePotPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("side_pot"), interrogationPDA.toBuffer()],
+        program.programId
+      );
+
+      const [bribeEscrowPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("bribe_escrow"), interrogationPDA.toBuffer()],
+        program.programId
+      );
+
+      const [lo, hi] =
+        Buffer.compare(playerA.publicKey.toBuffer(), playerB.publicKey.toBuffer()) < 0
+          ? [playerA.publicKey, playerB.publicKey]
+          : [playerB.publicKey, playerA.publicKey];
+      const [pairHistoryPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("pair_history"), lo.toBuffer(), hi.toBuffer()],
+        program.programId
+      );
+
+      const signature = await program.methods
         .selectPlayers(Array.from(randomSeed))
         .accounts({
           selector: authority.publicKey,
@@ -178,12 +307,21 @@ describe("$SNITCH Contract", () => {
           playerBToken: playerBTokenAccount,
           playerAProfile: playerAProfilePDA,
           playerBProfile: playerBProfilePDA,
+          playerA: playerA.publicKey,
+          playerB: playerB.publicKey,
+          playerATombstone: tombstoneOf(playerA.publicKey),
+          playerBTombstone: tombstoneOf(playerB.publicKey),
+          pairHistory: pairHistoryPDA,
           interrogation: interrogationPDA,
+          tokenMint: mint,
+          sidePot: sidePotPDA,
+          bribeEscrow: bribeEscrowPDA,
+          tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: SYSVAR_RENT_PUBKEY,
         })
         .signers([authority])
-        .rpc();
+        .rpc({ commitment: "confirmed" });
 
       const gameState = await program.account.gameState.fetch(gameStatePDA);
       assert.equal(gameState.currentRound.toNumber(), 1);
@@ -199,6 +337,31 @@ describe("$SNITCH Contract", () => {
       assert.equal(interrogation.playerA.toBase58(), playerA.publicKey.toBase58());
       assert.equal(interrogation.playerB.toBase58(), playerB.publicKey.toBase58());
       assert.deepEqual(interrogation.phase, { commit: {} });
+      // Players without a history start with a neutral trust score
+      const tx = await provider.connection.getTransaction(signature, {
+        commitment: "confirmed",
+        maxSupportedTransactionVersion: 0,
+      });
+      const events = [
+        ...new anchor.EventParser(program.programId, program.coder).parseLogs(
+          tx.meta.logMessages
+        ),
+      ];
+      const started = events.find((e) => e.name.toLowerCase() === "roundstarted");
+      assert.equal(started.data.trustScoreA, 5000);
+      assert.equal(started.data.trustScoreB, 5000);
+
+      // Payoff rules are frozen at selection
+      assert.equal(
+        interrogation.payoffConfig.snitchRewardBps,
+        gameState.config.snitchRewardBps
+      );
+
+      // The selector pays for the pair's first meeting
+      const pairHistory = await program.account.pairHistory.fetch(pairHistoryPDA);
+      assert.equal(pairHistory.playerLo.toBase58(), lo.toBase58());
+      assert.equal(pairHistory.playerHi.toBase58(), hi.toBase58());
+      assert.equal(pairHistory.roundsPlayed.toNumber(), 0);
     });
   });
 
@@ -206,6 +369,8 @@ describe("$SNITCH Contract", () => {
     let interrogationPDA: PublicKey;
     let playerAProfilePDA: PublicKey;
     let playerBProfilePDA: PublicKey;
+    let sidePotPDA: PublicKey;
+    let bribeEscrowPDA: PublicKey;
     let roundId = 1;
 
     before(async () => {
@@ -227,6 +392,34 @@ describe("$SNITCH Contract", () => {
         [Buffer.from("player"), playerB.publicKey.toBuffer()],
         program.programId
       );
+
+      [sidePotPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("side_pot"), interrogationPDA.toBuffer()],
+        program.programId
+      );
+
+      [bribeEscrowPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("bribe_escrow"), interrogationPDA.toBuffer()],
+        program.programId
+      );
+    });
+
+    it("Should let player A pledge to cooperate", async () => {
+      await program.methods
+        .pledge(0)
+        .accounts({
+          player: playerA.publicKey,
+          gameState: gameStatePDA,
+          interrogation: interrogationPDA,
+          playerProfile: playerAProfilePDA,
+        })
+        .signers([playerA])
+        .rpc();
+
+      const interrogation = await program.account.interrogation.fetch(
+        interrogationPDA
+      );
+      assert.deepEqual(interrogation.pledgeA, { cooperate: {} });
     });
 
     it("Should allow player A to commit", async () => {
@@ -246,12 +439,18 @@ describe("$SNITCH Contract", () => {
       );
 
       await program.methods
-        .commitChoice(Array.from(Buffer.from(commitment.slice(2), "hex")))
+        .commitChoice(
+          Array.from(Buffer.from(commitment.slice(2), "hex")),
+          new anchor.BN(0)
+        )
         .accounts({
-          player: playerA.publicKey,
+          signer: playerA.publicKey,
           gameState: gameStatePDA,
           interrogation: interrogationPDA,
           playerProfile: playerAProfilePDA,
+          playerToken: playerATokenAccount,
+          sidePot: sidePotPDA,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([playerA])
         .rpc();
@@ -262,6 +461,54 @@ describe("$SNITCH Contract", () => {
       assert.isNotNull(interrogation.commitmentA);
     });
 
+    it("Should let player A escrow a bribe for player B", async () => {
+      const bribe = new anchor.BN(500 * 10 ** MINT_DECIMALS);
+
+      await program.methods
+        .offerBribe(bribe)
+        .accounts({
+          briber: playerA.publicKey,
+          gameState: gameStatePDA,
+          interrogation: interrogationPDA,
+          briberToken: playerATokenAccount,
+          bribeEscrow: bribeEscrowPDA,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([playerA])
+        .rpc();
+
+      const interrogation = await program.account.interrogation.fetch(
+        interrogationPDA
+      );
+      assert.equal(interrogation.bribeA.toString(), bribe.toString());
+    });
+
+    it("Should let player B post to the interrogation room", async () => {
+      const [messageLogPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("message_log"), interrogationPDA.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .postMessage("I'll stay quiet if you do")
+        .accounts({
+          sender: playerB.publicKey,
+          gameState: gameStatePDA,
+          interrogation: interrogationPDA,
+          messageLog: messageLogPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([playerB])
+        .rpc();
+
+      const messageLog = await program.account.messageLog.fetch(messageLogPDA);
+      assert.equal(messageLog.messages.length, 1);
+      assert.equal(
+        messageLog.messages[0].sender.toBase58(),
+        playerB.publicKey.toBase58()
+      );
+    });
+
     it("Should allow player B to commit", async () => {
       // Choice: SNITCH (1)
       const choice = 1;
@@ -277,13 +524,22 @@ describe("$SNITCH Contract", () => {
         ])
       );
 
+      // Player B doubles down with a side wager
+      const sideWager = new anchor.BN(1_000 * 10 ** MINT_DECIMALS);
+
       await program.methods
-        .commitChoice(Array.from(Buffer.from(commitment.slice(2), "hex")))
+        .commitChoice(
+          Array.from(Buffer.from(commitment.slice(2), "hex")),
+          sideWager
+        )
         .accounts({
-          player: playerB.publicKey,
+          signer: playerB.publicKey,
           gameState: gameStatePDA,
           interrogation: interrogationPDA,
           playerProfile: playerBProfilePDA,
+          playerToken: playerBTokenAccount,
+          sidePot: sidePotPDA,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .signers([playerB])
         .rpc();
@@ -292,6 +548,7 @@ describe("$SNITCH Contract", () => {
         interrogationPDA
       );
       assert.isNotNull(interrogation.commitmentB);
+      assert.equal(interrogation.sidePotB.toString(), sideWager.toString());
     });
 
     it("Should allow player A to reveal", async () => {
@@ -307,10 +564,13 @@ describe("$SNITCH Contract", () => {
         await program.methods
           .revealChoice(choice, Array.from(salt))
           .accounts({
-            player: playerA.publicKey,
+            signer: playerA.publicKey,
             gameState: gameStatePDA,
             interrogation: interrogationPDA,
             playerProfile: playerAProfilePDA,
+            playerToken: playerATokenAccount,
+            yieldVault: yieldVaultPDA,
+            tokenProgram: TOKEN_PROGRAM_ID,
           })
           .signers([playerA])
           .rpc();
@@ -326,12 +586,114 @@ describe("$SNITCH Contract", () => {
     });
   });
 
+  describe("Session Delegates", () => {
+    it("Should register and revoke a delegate key", async () => {
+      const sessionKey = Keypair.generate();
+      const [playerAProfilePDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), playerA.publicKey.toBuffer()],
+        program.programId
+      );
+      const expiresAt = new anchor.BN(Math.floor(Date.now() / 1000) + 86400);
+
+      await program.methods
+        .setDelegate(sessionKey.publicKey, expiresAt)
+        .accounts({
+          player: playerA.publicKey,
+          playerProfile: playerAProfilePDA,
+          tombstone: tombstoneOf(playerA.publicKey),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([playerA])
+        .rpc();
+
+      let profile = await program.account.playerProfile.fetch(playerAProfilePDA);
+      assert.equal(profile.delegate.toBase58(), sessionKey.publicKey.toBase58());
+
+      await program.methods
+        .revokeDelegate()
+        .accounts({
+          player: playerA.publicKey,
+          playerProfile: playerAProfilePDA,
+        })
+        .signers([playerA])
+        .rpc();
+
+      profile = await program.account.playerProfile.fetch(playerAProfilePDA);
+      assert.isNull(profile.delegate);
+    });
+  });
+
+  describe("Autopilot", () => {
+    it("Should store an autopilot strategy", async () => {
+      const [playerBProfilePDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), playerB.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .setStrategy({ random: { snitchBiasBps: 2500 } })
+        .accounts({
+          player: playerB.publicKey,
+          playerProfile: playerBProfilePDA,
+          tombstone: tombstoneOf(playerB.publicKey),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([playerB])
+        .rpc();
+
+      const profile = await program.account.playerProfile.fetch(playerBProfilePDA);
+      assert.deepEqual(profile.strategy, { random: { snitchBiasBps: 2500 } });
+    });
+  });
+
+  describe("Migrations", () => {
+    it("Should leave a current profile untouched when migrated twice", async () => {
+      const [playerAProfilePDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), playerA.publicKey.toBuffer()],
+        program.programId
+      );
+
+      for (let i = 0; i < 2; i++) {
+        await program.methods
+          .migrateProfile()
+          .accounts({
+            payer: authority.publicKey,
+            player: playerA.publicKey,
+            playerProfile: playerAProfilePDA,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([authority])
+          .rpc();
+      }
+
+      const profile = await program.account.playerProfile.fetch(playerAProfilePDA);
+      assert.equal(profile.version, 2);
+      assert.equal(profile.player.toBase58(), playerA.publicKey.toBase58());
+      assert.equal(profile.rentPayer.toBase58(), authority.publicKey.toBase58());
+    });
+
+    it("Should migrate the game state idempotently", async () => {
+      await program.methods
+        .migrateGameState()
+        .accounts({
+          payer: authority.publicKey,
+          gameState: gameStatePDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+
+      const gameState = await program.account.gameState.fetch(gameStatePDA);
+      assert.equal(gameState.version, 8);
+    });
+  });
+
   describe("Admin Functions", () => {
     it("Should pause the game", async () => {
       await program.methods
         .pause()
         .accounts({
-          admin: authority.publicKey,
+          pauser: authority.publicKey,
           gameState: gameStatePDA,
         })
         .signers([authority])
@@ -345,7 +707,7 @@ describe("$SNITCH Contract", () => {
       await program.methods
         .resume()
         .accounts({
-          admin: authority.publicKey,
+          pauser: authority.publicKey,
           gameState: gameStatePDA,
         })
         .signers([authority])
@@ -355,7 +717,66 @@ describe("$SNITCH Contract", () => {
       assert.equal(gameState.paused, false);
     });
 
-    it("Should update config", async () => {
+    it("Should let an assigned pauser pause without the authority key", async () => {
+      const onCall = Keypair.generate();
+
+      await program.methods
+        .assignRole({ pauser: {} }, onCall.publicKey)
+        .accounts({
+          admin: authority.publicKey,
+          gameState: gameStatePDA,
+        })
+        .signers([authority])
+        .rpc();
+
+      await program.methods
+        .pause()
+        .accounts({
+          pauser: onCall.publicKey,
+          gameState: gameStatePDA,
+        })
+        .signers([onCall])
+        .rpc();
+
+      let gameState = await program.account.gameState.fetch(gameStatePDA);
+      assert.equal(gameState.paused, true);
+      assert.equal(gameState.roles.pauser.toBase58(), onCall.publicKey.toBase58());
+
+      await program.methods
+        .resume()
+        .accounts({
+          pauser: onCall.publicKey,
+          gameState: gameStatePDA,
+        })
+        .signers([onCall])
+        .rpc();
+
+      // The pauser cannot rewrite payoffs
+      try {
+        await program.methods
+          .updateConfig(gameState.config)
+          .accounts({
+            admin: onCall.publicKey,
+            gameState: gameStatePDA,
+          })
+          .signers([onCall])
+          .rpc();
+        assert.fail("Pauser updated the config");
+      } catch (e) {
+        assert.include(e.toString(), "Unauthorized");
+      }
+
+      await program.methods
+        .assignRole({ pauser: {} }, null)
+        .accounts({
+          admin: authority.publicKey,
+          gameState: gameStatePDA,
+        })
+        .signers([authority])
+        .rpc();
+    });
+
+    it("Should stage a config update behind the timelock", async () => {
       const newConfig = {
         minBalanceThreshold: new anchor.BN(INITIAL_SUPPLY / 500), // 0.2%
         cooldownPeriod: new anchor.BN(604800),
@@ -368,6 +789,12 @@ describe("$SNITCH Contract", () => {
         bpsDenominator: 10000,
         useVrf: false,
         vrfOracle: null,
+        lawyerFeeBps: 200,
+        lawyerProtectionBps: 5000,
+        commitmentRevisionFee: new anchor.BN(0),
+        forfeitPenaltyBps: 10000,
+        streakBonusBps: 0,
+        maxStreakBonusBps: 0,
       };
 
       await program.methods
@@ -379,8 +806,961 @@ describe("$SNITCH Contract", () => {
         .signers([authority])
         .rpc();
 
-      const gameState = await program.account.gameState.fetch(gameStatePDA);
-      assert.equal(gameState.config.cooperationYieldBps, 600);
+      let gameState = await program.account.gameState.fetch(gameStatePDA);
+      assert.equal(gameState.config.cooperationYieldBps, 500);
+      assert.equal(gameState.pendingConfig.cooperationYieldBps, 600);
+      assert.isAbove(gameState.pendingConfigActivatesAt.toNumber(), 0);
+
+      // A pending config is never silently replaced
+      try {
+        await program.methods
+          .updateConfig({ ...newConfig, cooperationYieldBps: 700 })
+          .accounts({
+            admin: authority.publicKey,
+            gameState: gameStatePDA,
+          })
+          .signers([authority])
+          .rpc();
+        assert.fail("Replaced a pending config");
+      } catch (e) {
+        assert.include(e.toString(), "ConfigPending");
+      }
+
+      // Too early to apply
+      try {
+        await program.methods
+          .applyConfig()
+          .accounts({ gameState: gameStatePDA })
+          .rpc();
+        assert.fail("Config applied before the timelock");
+      } catch (e) {
+        assert.include(e.toString(), "ConfigTimelockActive");
+      }
+
+      await program.methods
+        .cancelConfig()
+        .accounts({
+          admin: authority.publicKey,
+          gameState: gameStatePDA,
+        })
+        .signers([authority])
+        .rpc();
+
+      gameState = await program.account.gameState.fetch(gameStatePDA);
+      assert.isNull(gameState.pendingConfig);
+    });
+
+    it("Should transfer authority in two steps and back", async () => {
+      const newAuthority = Keypair.generate();
+
+      await program.methods
+        .proposeAuthority(newAuthority.publicKey)
+        .accounts({
+          admin: authority.publicKey,
+          gameState: gameStatePDA,
+        })
+        .signers([authority])
+        .rpc();
+
+      let gameState = await program.account.gameState.fetch(gameStatePDA);
+      assert.equal(gameState.authority.toBase58(), authority.publicKey.toBase58());
+      assert.equal(
+        gameState.pendingAuthority.toBase58(),
+        newAuthority.publicKey.toBase58()
+      );
+
+      await program.methods
+        .acceptAuthority()
+        .accounts({
+          newAuthority: newAuthority.publicKey,
+          gameState: gameStatePDA,
+        })
+        .signers([newAuthority])
+        .rpc();
+
+      gameState = await program.account.gameState.fetch(gameStatePDA);
+      assert.equal(gameState.authority.toBase58(), newAuthority.publicKey.toBase58());
+      assert.isNull(gameState.pendingAuthority);
+
+      // Hand it back for the remaining tests
+      await program.methods
+        .proposeAuthority(authority.publicKey)
+        .accounts({
+          admin: newAuthority.publicKey,
+          gameState: gameStatePDA,
+        })
+        .signers([newAuthority])
+        .rpc();
+      await program.methods
+        .acceptAuthority()
+        .accounts({
+          newAuthority: authority.publicKey,
+          gameState: gameStatePDA,
+        })
+        .signers([authority])
+        .rpc();
+    });
+
+    it("Should cancel the active round and refund escrows", async () => {
+      const [interrogationPDA] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("interrogation"),
+          gameStatePDA.toBuffer(),
+          new anchor.BN(1).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const [playerAProfilePDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), playerA.publicKey.toBuffer()],
+        program.programId
+      );
+      const [playerBProfilePDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), playerB.publicKey.toBuffer()],
+        program.programId
+      );
+      const [sidePotPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("side_pot"), interrogationPDA.toBuffer()],
+        program.programId
+      );
+      const [bribeEscrowPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("bribe_escrow"), interrogationPDA.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .cancelRound()
+        .accounts({
+          admin: authority.publicKey,
+          gameState: gameStatePDA,
+          interrogation: interrogationPDA,
+          playerAProfile: playerAProfilePDA,
+          playerBProfile: playerBProfilePDA,
+          playerAToken: playerATokenAccount,
+          playerBToken: playerBTokenAccount,
+          yieldVault: yieldVaultPDA,
+          sidePot: sidePotPDA,
+          bribeEscrow: bribeEscrowPDA,
+          tokenAuthority: gameStatePDA,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([authority])
+        .rpc();
+
+      const gameState = await program.account.gameState.fetch(gameStatePDA);
+      assert.isNull(gameState.activeInterrogation);
+
+      const interrogation = await program.account.interrogation.fetch(
+        interrogationPDA
+      );
+      assert.deepEqual(interrogation.outcome, { cancelled: {} });
+      assert.equal(interrogation.pnlA.sidePot.toNumber(), 0);
+      assert.equal(interrogation.pnlA.bribes.toNumber(), 0);
+
+      const profileA = await program.account.playerProfile.fetch(playerAProfilePDA);
+      assert.equal(profileA.currentRound.toNumber(), 0);
+      assert.equal(profileA.onCooldown, false);
+      // The cancelled round no longer counts as a selection
+      assert.equal(profileA.timesSelected.toNumber(), 0);
+      assert.equal(profileA.lastSelectedAt.toNumber(), 0);
+      assert.equal(profileA.totalPenaltiesPaid.toNumber(), 0);
+      // Refunds leave no realized profit or loss behind
+      assert.equal(profileA.pnlGains.toNumber(), 0);
+      assert.equal(profileA.pnlLosses.toNumber(), 0);
+    });
+
+    it("Should keep a finished round until its retention window passes", async () => {
+      const [interrogationPDA] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("interrogation"),
+          gameStatePDA.toBuffer(),
+          new anchor.BN(1).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const [sidePotPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("side_pot"), interrogationPDA.toBuffer()],
+        program.programId
+      );
+      const [bribeEscrowPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("bribe_escrow"), interrogationPDA.toBuffer()],
+        program.programId
+      );
+      const [messageLogPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("message_log"), interrogationPDA.toBuffer()],
+        program.programId
+      );
+
+      const messageLog = await program.account.messageLog.fetch(messageLogPDA);
+      assert.equal(messageLog.payer.toBase58(), playerB.publicKey.toBase58());
+
+      try {
+        await program.methods
+          .closeInterrogation()
+          .accounts({
+            closer: authority.publicKey,
+            gameState: gameStatePDA,
+            interrogation: interrogationPDA,
+            payer: authority.publicKey,
+            sidePot: sidePotPDA,
+            bribeEscrow: bribeEscrowPDA,
+            messageLog: messageLogPDA,
+            messageLogPayer: playerB.publicKey,
+            tokenAuthority: gameStatePDA,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([authority])
+          .rpc();
+        assert.fail("Round closed inside its retention window");
+      } catch (e) {
+        assert.include(e.toString(), "RetentionPeriodActive");
+      }
+    });
+
+    it("Should close a profile with nothing pending", async () => {
+      const [playerAProfilePDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), playerA.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .closeProfile()
+        .accounts({
+          player: playerA.publicKey,
+          playerProfile: playerAProfilePDA,
+          tombstone: tombstoneOf(playerA.publicKey),
+          rentPayer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([playerA])
+        .rpc();
+
+      // The profile is gone; its record waits in the tombstone
+      assert.isNull(await provider.connection.getAccountInfo(playerAProfilePDA));
+      const tombstone = await program.account.profileTombstone.fetch(
+        tombstoneOf(playerA.publicKey)
+      );
+      assert.equal(tombstone.player.toBase58(), playerA.publicKey.toBase58());
+      assert.equal(tombstone.timesSelected.toNumber(), 1);
+    });
+
+    it("Should restore a closed profile's record when it is re-created", async () => {
+      const [playerAProfilePDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), playerA.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .setStrategy({ titForTat: {} })
+        .accounts({
+          player: playerA.publicKey,
+          playerProfile: playerAProfilePDA,
+          tombstone: tombstoneOf(playerA.publicKey),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([playerA])
+        .rpc();
+
+      const profile = await program.account.playerProfile.fetch(playerAProfilePDA);
+      assert.equal(profile.timesSelected.toNumber(), 1);
+      assert.deepEqual(profile.strategy, { titForTat: {} });
+      assert.isNull(
+        await provider.connection.getAccountInfo(tombstoneOf(playerA.publicKey))
+      );
+    });
+
+    it("Should pause through a 2-of-2 multisig proposal", async () => {
+      cosigner = Keypair.generate();
+
+      await program.methods
+        .setMultisig([authority.publicKey, cosigner.publicKey], 2)
+        .accounts({
+          admin: authority.publicKey,
+          gameState: gameStatePDA,
+        })
+        .signers([authority])
+        .rpc();
+
+      const [proposalPDA] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("multisig_proposal"),
+          gameStatePDA.toBuffer(),
+          new anchor.BN(0).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createMultisigProposal({ pause: {} })
+        .accounts({
+          member: authority.publicKey,
+          gameState: gameStatePDA,
+          proposal: proposalPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+
+      const executeAccounts = {
+        executor: authority.publicKey,
+        gameState: gameStatePDA,
+        proposal: proposalPDA,
+        proposer: authority.publicKey,
+        playerProfile: null,
+      };
+
+      // One approval is not enough
+      try {
+        await program.methods
+          .executeMultisigProposal()
+          .accounts(executeAccounts)
+          .signers([authority])
+          .rpc();
+        assert.fail("Executed below threshold");
+      } catch (e) {
+        assert.include(e.toString(), "ThresholdNotMet");
+      }
+
+      await program.methods
+        .approveMultisigProposal()
+        .accounts({
+          member: cosigner.publicKey,
+          gameState: gameStatePDA,
+          proposal: proposalPDA,
+        })
+        .signers([cosigner])
+        .rpc();
+
+      await program.methods
+        .executeMultisigProposal()
+        .accounts(executeAccounts)
+        .signers([authority])
+        .rpc();
+
+      let gameState = await program.account.gameState.fetch(gameStatePDA);
+      assert.equal(gameState.paused, true);
+      assert.equal(gameState.multisig.proposalCount.toNumber(), 1);
+      // Executed proposals are closed, refunding the proposer
+      assert.isNull(await provider.connection.getAccountInfo(proposalPDA));
+
+      // Resuming now needs the multisig
+      try {
+        await program.methods
+          .resume()
+          .accounts({
+            pauser: authority.publicKey,
+            gameState: gameStatePDA,
+          })
+          .signers([authority])
+          .rpc();
+        assert.fail("Resumed with a single key");
+      } catch (e) {
+        assert.include(e.toString(), "MultisigRequired");
+      }
+
+      await runMultisigAction({ resume: {} });
+
+      gameState = await program.account.gameState.fetch(gameStatePDA);
+      assert.equal(gameState.paused, false);
+
+      // Pausing stays single-key for emergencies
+      await program.methods
+        .pause()
+        .accounts({
+          pauser: authority.publicKey,
+          gameState: gameStatePDA,
+        })
+        .signers([authority])
+        .rpc();
+      await runMultisigAction({ resume: {} });
+    });
+
+    it("Should route admin actions through the multisig once enabled", async () => {
+      const keeper = Keypair.generate();
+      try {
+        await program.methods
+          .assignRole({ selector: {} }, keeper.publicKey)
+          .accounts({
+            admin: authority.publicKey,
+            gameState: gameStatePDA,
+          })
+          .signers([authority])
+          .rpc();
+        assert.fail("Assigned a role with a single key");
+      } catch (e) {
+        assert.include(e.toString(), "MultisigRequired");
+      }
+
+      await runMultisigAction({
+        assignRole: { role: { selector: {} }, holder: keeper.publicKey },
+      });
+      let gameState = await program.account.gameState.fetch(gameStatePDA);
+      assert.equal(gameState.roles.selector.toBase58(), keeper.publicKey.toBase58());
+
+      await runMultisigAction({ assignRole: { role: { selector: {} }, holder: null } });
+      gameState = await program.account.gameState.fetch(gameStatePDA);
+      assert.isNull(gameState.roles.selector);
+    });
+  });
+
+  describe("Forfeit", () => {
+    let playerC: Keypair;
+    let playerCTokenAccount: PublicKey;
+    let interrogationPDA: PublicKey;
+    let forfeitAccounts: any;
+
+    before(async () => {
+      playerC = Keypair.generate();
+      await provider.connection.requestAirdrop(playerC.publicKey, 1 * 10 ** 9);
+      const playerCToken = await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        authority,
+        mint,
+        playerC.publicKey
+      );
+      playerCTokenAccount = playerCToken.address;
+      await mintTo(
+        provider.connection,
+        authority,
+        mint,
+        playerCTokenAccount,
+        authority,
+        INITIAL_SUPPLY / 100
+      );
+
+      // Round 2: B (released by the cancel) against C
+      [interrogationPDA] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("interrogation"),
+          gameStatePDA.toBuffer(),
+          new anchor.BN(2).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const [playerBProfilePDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), playerB.publicKey.toBuffer()],
+        program.programId
+      );
+      const [playerCProfilePDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), playerC.publicKey.toBuffer()],
+        program.programId
+      );
+      const [sidePotPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("side_pot"), interrogationPDA.toBuffer()],
+        program.programId
+      );
+      const [bribeEscrowPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("bribe_escrow"), interrogationPDA.toBuffer()],
+        program.programId
+      );
+      const [lo, hi] =
+        Buffer.compare(playerB.publicKey.toBuffer(), playerC.publicKey.toBuffer()) < 0
+          ? [playerB.publicKey, playerC.publicKey]
+          : [playerC.publicKey, playerB.publicKey];
+      const [pairHistoryPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("pair_history"), lo.toBuffer(), hi.toBuffer()],
+        program.programId
+      );
+      const [leaderboardPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("leaderboard"), gameStatePDA.toBuffer()],
+        program.programId
+      );
+      const [roundHistoryPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("round_history"), gameStatePDA.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .selectPlayers(Array.from(Buffer.alloc(32, 0x07)))
+        .accounts({
+          selector: authority.publicKey,
+          gameState: gameStatePDA,
+          playerAToken: playerBTokenAccount,
+          playerBToken: playerCTokenAccount,
+          playerAProfile: playerBProfilePDA,
+          playerBProfile: playerCProfilePDA,
+          playerA: playerB.publicKey,
+          playerB: playerC.publicKey,
+          playerATombstone: tombstoneOf(playerB.publicKey),
+          playerBTombstone: tombstoneOf(playerC.publicKey),
+          pairHistory: pairHistoryPDA,
+          interrogation: interrogationPDA,
+          tokenMint: mint,
+          sidePot: sidePotPDA,
+          bribeEscrow: bribeEscrowPDA,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .signers([authority])
+        .rpc();
+
+      forfeitAccounts = {
+        gameState: gameStatePDA,
+        interrogation: interrogationPDA,
+        playerAProfile: playerBProfilePDA,
+        playerBProfile: playerCProfilePDA,
+        pairHistory: pairHistoryPDA,
+        leaderboard: leaderboardPDA,
+        roundHistory: roundHistoryPDA,
+        playerAToken: playerBTokenAccount,
+        playerBToken: playerCTokenAccount,
+        yieldVault: yieldVaultPDA,
+        sidePot: sidePotPDA,
+        bribeEscrow: bribeEscrowPDA,
+        tokenMint: mint,
+        tokenAuthority: gameStatePDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      };
+    });
+
+    it("Should reject a forfeit from a player outside the round", async () => {
+      try {
+        await program.methods
+          .forfeit()
+          .accounts({ ...forfeitAccounts, resolver: authority.publicKey })
+          .signers([authority])
+          .rpc();
+        assert.fail("Outsider forfeited the round");
+      } catch (e) {
+        assert.include(e.toString(), "PlayerNotInInterrogation");
+      }
+    });
+
+    it("Should not cancel a round with a single key once the multisig is on", async () => {
+      try {
+        await program.methods
+          .cancelRound()
+          .accounts({
+            admin: authority.publicKey,
+            gameState: gameStatePDA,
+            interrogation: interrogationPDA,
+            playerAProfile: forfeitAccounts.playerAProfile,
+            playerBProfile: forfeitAccounts.playerBProfile,
+            playerAToken: playerBTokenAccount,
+            playerBToken: playerCTokenAccount,
+            yieldVault: yieldVaultPDA,
+            sidePot: forfeitAccounts.sidePot,
+            bribeEscrow: forfeitAccounts.bribeEscrow,
+            tokenAuthority: gameStatePDA,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([authority])
+          .rpc();
+        assert.fail("Cancelled a round with a single key");
+      } catch (e) {
+        assert.include(e.toString(), "MultisigRequired");
+      }
+    });
+
+    it("Should require the leaderboard once it is initialized", async () => {
+      try {
+        await program.methods
+          .forfeit()
+          .accounts({
+            ...forfeitAccounts,
+            resolver: playerC.publicKey,
+            leaderboard: null,
+          })
+          .signers([playerC])
+          .rpc();
+        assert.fail("Resolved without the leaderboard");
+      } catch (e) {
+        assert.include(e.toString(), "MissingAccount");
+      }
+    });
+
+    it("Should require the round history once it is initialized", async () => {
+      try {
+        await program.methods
+          .forfeit()
+          .accounts({
+            ...forfeitAccounts,
+            resolver: playerC.publicKey,
+            roundHistory: null,
+          })
+          .signers([playerC])
+          .rpc();
+        assert.fail("Resolved without the round history");
+      } catch (e) {
+        assert.include(e.toString(), "MissingAccount");
+      }
+    });
+
+    it("Should let a player forfeit without touching history or stats", async () => {
+      await program.methods
+        .forfeit()
+        .accounts({ ...forfeitAccounts, resolver: playerC.publicKey })
+        .signers([playerC])
+        .rpc();
+
+      const interrogation = await program.account.interrogation.fetch(
+        interrogationPDA
+      );
+      assert.deepEqual(interrogation.outcome, { playerASnitched: {} });
+      assert.equal(
+        interrogation.forfeitedBy.toBase58(),
+        playerC.publicKey.toBase58()
+      );
+
+      const profileB = await program.account.playerProfile.fetch(
+        forfeitAccounts.playerAProfile
+      );
+      const profileC = await program.account.playerProfile.fetch(
+        forfeitAccounts.playerBProfile
+      );
+      // The forfeit is settled, but nobody made a real decision
+      assert.isAbove(profileB.pendingRewards.toNumber(), 0);
+      assert.isAbove(profileC.totalPenaltiesPaid.toNumber(), 0);
+      for (const profile of [profileB, profileC]) {
+        assert.equal(profile.currentRound.toNumber(), 0);
+        assert.isNull(profile.lastChoice);
+        assert.equal(profile.badgesEarned, 0);
+        assert.equal(profile.snitchStreak, 0);
+        assert.equal(profile.survivalStreak, 0);
+        assert.equal(profile.timesSnitched.toNumber(), 0);
+      }
+
+      const pairHistory = await program.account.pairHistory.fetch(
+        forfeitAccounts.pairHistory
+      );
+      assert.equal(pairHistory.roundsPlayed.toNumber(), 0);
+    });
+
+    it("Should not close a profile on cooldown", async () => {
+      try {
+        await program.methods
+          .closeProfile()
+          .accounts({
+            player: playerC.publicKey,
+            playerProfile: forfeitAccounts.playerBProfile,
+            tombstone: tombstoneOf(playerC.publicKey),
+            rentPayer: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([playerC])
+          .rpc();
+        assert.fail("Closed a profile on cooldown");
+      } catch (e) {
+        assert.include(e.toString(), "PlayerOnCooldown");
+      }
+    });
+
+    it("Should reject a forfeit once the round is resolved", async () => {
+      try {
+        await program.methods
+          .forfeit()
+          .accounts({ ...forfeitAccounts, resolver: playerB.publicKey })
+          .signers([playerB])
+          .rpc();
+        assert.fail("Forfeited a resolved round");
+      } catch (e) {
+        assert.include(e.toString(), "NoActiveRound");
+      }
+    });
+  });
+
+  describe("Badges", () => {
+    const TOKEN_METADATA_PROGRAM_ID = new PublicKey(
+      "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+    );
+
+    it("Should refuse to mint a badge that was not earned", async () => {
+      const [playerBProfilePDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("player"), playerB.publicKey.toBuffer()],
+        program.programId
+      );
+      // FirstBetrayal; B only won by forfeit, which awards nothing
+      const [badgeMintPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("badge"), playerB.publicKey.toBuffer(), Buffer.from([0])],
+        program.programId
+      );
+      const [metadataPDA] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("metadata"),
+          TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+          badgeMintPDA.toBuffer(),
+        ],
+        TOKEN_METADATA_PROGRAM_ID
+      );
+
+      try {
+        await program.methods
+          .mintBadge({ firstBetrayal: {} })
+          .accounts({
+            player: playerB.publicKey,
+            gameState: gameStatePDA,
+            playerProfile: playerBProfilePDA,
+            badgeMint: badgeMintPDA,
+            badgeToken: getAssociatedTokenAddressSync(badgeMintPDA, playerB.publicKey),
+            metadata: metadataPDA,
+            tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            rent: SYSVAR_RENT_PUBKEY,
+          })
+          .signers([playerB])
+          .rpc();
+        assert.fail("Minted an unearned badge");
+      } catch (e) {
+        assert.include(e.toString(), "BadgeNotEarned");
+      }
+
+      const info = await provider.connection.getAccountInfo(badgeMintPDA);
+      assert.isNull(info);
+    });
+  });
+
+  describe("Governance", () => {
+    it("Should create a proposal and lock tokens as voting weight", async () => {
+      let gameState = await program.account.gameState.fetch(gameStatePDA);
+      const [proposalPDA] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("gov_proposal"),
+          gameStatePDA.toBuffer(),
+          new anchor.BN(0).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const [voteEscrowPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote_escrow"), proposalPDA.toBuffer()],
+        program.programId
+      );
+      const [voteRecordPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote"), proposalPDA.toBuffer(), playerB.publicKey.toBuffer()],
+        program.programId
+      );
+
+      const proposedConfig = { ...gameState.config, snitchRewardBps: 4000 };
+
+      await program.methods
+        .createGovernanceProposal(proposedConfig)
+        .accounts({
+          proposer: playerA.publicKey,
+          gameState: gameStatePDA,
+          proposerToken: playerATokenAccount,
+          proposal: proposalPDA,
+          tokenMint: mint,
+          voteEscrow: voteEscrowPDA,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([playerA])
+        .rpc();
+
+      const voteAmount = new anchor.BN(1_000_000);
+      await program.methods
+        .castVote(voteAmount, true)
+        .accounts({
+          voter: playerB.publicKey,
+          gameState: gameStatePDA,
+          proposal: proposalPDA,
+          voteRecord: voteRecordPDA,
+          voterToken: playerBTokenAccount,
+          voteEscrow: voteEscrowPDA,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([playerB])
+        .rpc();
+
+      const proposal = await program.account.governanceProposal.fetch(proposalPDA);
+      assert.equal(proposal.votesFor.toNumber(), voteAmount.toNumber());
+      assert.equal(proposal.config.snitchRewardBps, 4000);
+
+      // Still inside the voting window
+      try {
+        await program.methods
+          .executeGovernanceProposal()
+          .accounts({
+            executor: playerA.publicKey,
+            gameState: gameStatePDA,
+            proposal: proposalPDA,
+          })
+          .signers([playerA])
+          .rpc();
+        assert.fail("Executed while voting was open");
+      } catch (e) {
+        assert.include(e.toString(), "VotingActive");
+      }
+
+      gameState = await program.account.gameState.fetch(gameStatePDA);
+      assert.equal(gameState.governance.proposalCount.toNumber(), 1);
+      // The default quorum is 10x the selection threshold
+      assert.equal(
+        gameState.governance.quorum.toString(),
+        gameState.config.minBalanceThreshold.muln(10).toString()
+      );
+    });
+
+    it("Should execute a passed proposal, return locked votes and close it", async () => {
+      // With the multisig on, governance parameters need its approval
+      const quorum = new anchor.BN(2_000_000);
+      try {
+        await program.methods
+          .setGovernance(new anchor.BN(2), quorum)
+          .accounts({
+            admin: authority.publicKey,
+            gameState: gameStatePDA,
+          })
+          .signers([authority])
+          .rpc();
+        assert.fail("Set governance with a single key");
+      } catch (e) {
+        assert.include(e.toString(), "MultisigRequired");
+      }
+
+      try {
+        await runMultisigAction({
+          setGovernance: { votingPeriod: new anchor.BN(2), quorum: new anchor.BN(0) },
+        });
+        assert.fail("Proposed a zero quorum");
+      } catch (e) {
+        assert.include(e.toString(), "InvalidConfig");
+      }
+
+      await runMultisigAction({
+        setGovernance: { votingPeriod: new anchor.BN(2), quorum },
+      });
+
+      let gameState = await program.account.gameState.fetch(gameStatePDA);
+      const [proposalPDA] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("gov_proposal"),
+          gameStatePDA.toBuffer(),
+          new anchor.BN(1).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const [voteEscrowPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote_escrow"), proposalPDA.toBuffer()],
+        program.programId
+      );
+      const voteRecord = (voter: PublicKey) =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("vote"), proposalPDA.toBuffer(), voter.toBuffer()],
+          program.programId
+        )[0];
+
+      await program.methods
+        .createGovernanceProposal({ ...gameState.config, snitchRewardBps: 4000 })
+        .accounts({
+          proposer: playerA.publicKey,
+          gameState: gameStatePDA,
+          proposerToken: playerATokenAccount,
+          proposal: proposalPDA,
+          tokenMint: mint,
+          voteEscrow: voteEscrowPDA,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([playerA])
+        .rpc();
+
+      const balanceBefore = (await getAccount(provider.connection, playerBTokenAccount)).amount;
+
+      // B carries the quorum for, A votes against with less
+      for (const [voter, token, amount, support] of [
+        [playerB, playerBTokenAccount, quorum, true],
+        [playerA, playerATokenAccount, new anchor.BN(1_000_000), false],
+      ] as [Keypair, PublicKey, anchor.BN, boolean][]) {
+        await program.methods
+          .castVote(amount, support)
+          .accounts({
+            voter: voter.publicKey,
+            gameState: gameStatePDA,
+            proposal: proposalPDA,
+            voteRecord: voteRecord(voter.publicKey),
+            voterToken: token,
+            voteEscrow: voteEscrowPDA,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([voter])
+          .rpc();
+      }
+
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+
+      const closeAccounts = {
+        closer: authority.publicKey,
+        gameState: gameStatePDA,
+        proposal: proposalPDA,
+        proposer: playerA.publicKey,
+        voteEscrow: voteEscrowPDA,
+        tokenAuthority: gameStatePDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      };
+
+      // A passed proposal stays until it is executed or expires
+      try {
+        await program.methods
+          .closeGovernanceProposal()
+          .accounts(closeAccounts)
+          .signers([authority])
+          .rpc();
+        assert.fail("Closed an executable proposal");
+      } catch (e) {
+        assert.include(e.toString(), "ProposalExecutable");
+      }
+
+      await program.methods
+        .executeGovernanceProposal()
+        .accounts({
+          executor: authority.publicKey,
+          gameState: gameStatePDA,
+          proposal: proposalPDA,
+        })
+        .signers([authority])
+        .rpc();
+
+      gameState = await program.account.gameState.fetch(gameStatePDA);
+      assert.equal(gameState.pendingConfig.snitchRewardBps, 4000);
+
+      // Locked votes must be returned first
+      try {
+        await program.methods
+          .closeGovernanceProposal()
+          .accounts(closeAccounts)
+          .signers([authority])
+          .rpc();
+        assert.fail("Closed a proposal holding votes");
+      } catch (e) {
+        assert.include(e.toString(), "VotesOutstanding");
+      }
+
+      for (const [voter, token] of [
+        [playerB, playerBTokenAccount],
+        [playerA, playerATokenAccount],
+      ] as [Keypair, PublicKey][]) {
+        await program.methods
+          .withdrawVote()
+          .accounts({
+            voter: voter.publicKey,
+            gameState: gameStatePDA,
+            proposal: proposalPDA,
+            voteRecord: voteRecord(voter.publicKey),
+            voterToken: token,
+            voteEscrow: voteEscrowPDA,
+            tokenAuthority: gameStatePDA,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([voter])
+          .rpc();
+      }
+
+      const balanceAfter = (await getAccount(provider.connection, playerBTokenAccount)).amount;
+      assert.equal(balanceAfter.toString(), balanceBefore.toString());
+
+      await program.methods
+        .closeGovernanceProposal()
+        .accounts(closeAccounts)
+        .signers([authority])
+        .rpc();
+
+      assert.isNull(await provider.connection.getAccountInfo(proposalPDA));
+      assert.isNull(await provider.connection.getAccountInfo(voteEscrowPDA));
     });
   });
 });